@@ -0,0 +1,79 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use kled::prelude::*;
+
+const ITEMS: usize = 10_000;
+
+// Compares a chain of ten operators implemented on top of the raw observer traits (map) with a
+// chain of ten operators which allocate a future per item (do_on_next).
+fn operator_chain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("operator_chain");
+    group.throughput(Throughput::Elements(ITEMS as u64));
+    group.bench_function("raw_map_x10", |b| {
+        b.iter(|| {
+            async_std::task::block_on(async {
+                (0..ITEMS)
+                    .into_observable()
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .map(|a| a + 1)
+                    .subscribe_next(|item| {
+                        black_box(item);
+                    })
+                    .await;
+            })
+        })
+    });
+    group.bench_function("async_do_on_next_x10", |b| {
+        b.iter(|| {
+            async_std::task::block_on(async {
+                (0..ITEMS)
+                    .into_observable()
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .do_on_next(|a| {
+                        black_box(a);
+                    })
+                    .subscribe_next(|item| {
+                        black_box(item);
+                    })
+                    .await;
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, operator_chain);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use kled::prelude::*;
+
+const ITEMS: usize = 10_000;
+
+fn map_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_scan");
+    group.throughput(Throughput::Elements(ITEMS as u64));
+    group.bench_function("observable", |b| {
+        b.iter(|| {
+            async_std::task::block_on(async {
+                (0..ITEMS)
+                    .into_observable()
+                    .map(|a| a * 2)
+                    .scan(0, |a, b| a + b)
+                    .subscribe_next(|item| {
+                        black_box(item);
+                    })
+                    .await;
+            })
+        })
+    });
+    group.bench_function("flow", |b| {
+        b.iter(|| {
+            async_std::task::block_on(async {
+                (0..ITEMS)
+                    .into_flow()
+                    .map(|a| a * 2)
+                    .scan(0, |a, b| a + b)
+                    .subscribe_next(|item| {
+                        black_box(item);
+                    })
+                    .await;
+            })
+        })
+    });
+    group.finish();
+}
+
+// The source ignores demand, while the subscriber requests in batches of 64 items, so that the
+// buffer regularly overflows.
+fn on_backpressure_buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("on_backpressure_buffer");
+    group.throughput(Throughput::Elements(ITEMS as u64));
+    for strategy in [
+        flow::BufferStrategy::Error,
+        flow::BufferStrategy::DropLatest,
+        flow::BufferStrategy::DropOldest,
+    ]
+    .iter()
+    {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", strategy)),
+            strategy,
+            |b, strategy| {
+                b.iter(|| {
+                    async_std::task::block_on(async {
+                        (0..ITEMS)
+                            .into_observable()
+                            .to_flow(flow::BackpressureStrategy::Missing)
+                            .on_backpressure_buffer_with_capacity(*strategy, 128)
+                            .rebatch_requests(64)
+                            .subscribe_all(
+                                |item| {
+                                    black_box(item);
+                                },
+                                |_| {},
+                                || {},
+                            )
+                            .await;
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, map_scan, on_backpressure_buffer);
+criterion_main!(benches);
@@ -0,0 +1,82 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use kled::prelude::*;
+use kled::subscription::ArcSubscriptionStub;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const CALLS: usize = 10_000;
+
+// Counts all allocations, so that the allocations per call can be reported next to the
+// timings, which criterion does not measure.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_per_call<CallsFn>(calls_fn: CallsFn) -> f64
+where
+    CallsFn: FnOnce(),
+{
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    calls_fn();
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / CALLS as f64
+}
+
+fn try_request_now(subscription: &impl Subscription) {
+    for _ in 0..CALLS {
+        black_box(subscription.try_request_now(1));
+    }
+}
+
+fn request(subscription: &impl Subscription) {
+    async_std::task::block_on(async {
+        for _ in 0..CALLS {
+            subscription.request(1).await;
+        }
+    })
+}
+
+// Compares the synchronous fast path of a subscription with the async request, which boxes
+// a future per call.
+fn request_paths(c: &mut Criterion) {
+    let stub = ArcSubscriptionStub::default();
+    let subscription = stub.subscription();
+    println!(
+        "request/allocations per call: try_request_now {:.2}, request {:.2}",
+        allocations_per_call(|| try_request_now(&subscription)),
+        allocations_per_call(|| request(&subscription)),
+    );
+    stub.get_and_reset_requested();
+
+    let mut group = c.benchmark_group("request");
+    group.throughput(Throughput::Elements(CALLS as u64));
+    group.bench_function("try_request_now", |b| {
+        b.iter(|| {
+            try_request_now(&subscription);
+            stub.get_and_reset_requested();
+        })
+    });
+    group.bench_function("request", |b| {
+        b.iter(|| {
+            request(&subscription);
+            stub.get_and_reset_requested();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, request_paths);
+criterion_main!(benches);
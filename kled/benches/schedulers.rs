@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use futures::prelude::*;
+use kled::prelude::*;
+use std::time::Duration;
+
+const ITEMS: usize = 10_000;
+
+fn observe_on<Scheduler>(scheduler: Scheduler)
+where
+    Scheduler: kled::core::Scheduler,
+{
+    async_std::task::block_on(async {
+        (0..ITEMS)
+            .into_flow()
+            .observe_on(scheduler.clone(), 128, false)
+            .subscribe_next(|item| {
+                black_box(item);
+            })
+            .await;
+    });
+    scheduler.join();
+}
+
+fn observe_on_schedulers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("observe_on");
+    group.throughput(Throughput::Elements(ITEMS as u64));
+    group.bench_function("thread_pool", |b| {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        b.iter(|| observe_on(scheduler.clone()))
+    });
+    group.bench_function("new_thread", |b| {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        b.iter(|| observe_on(scheduler.clone()))
+    });
+    group.finish();
+}
+
+// Sends items with delays in reverse order of their sending, so that the receiver has to
+// reorder all of them.
+fn delay_receiver_ordering(c: &mut Criterion) {
+    let mut group = c.benchmark_group("delay_receiver");
+    group.throughput(Throughput::Elements(ITEMS as u64));
+    group.bench_function("ordering", |b| {
+        b.iter(|| {
+            async_std::task::block_on(async {
+                let (mut tx, mut rx) = scheduler::unbounded();
+                for item in 0..ITEMS {
+                    let delay = Duration::from_nanos((ITEMS - item) as u64);
+                    tx.send_delayed(delay, item).await.unwrap();
+                }
+                drop(tx);
+                while let Some(item) = rx.next().await {
+                    black_box(item);
+                }
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, observe_on_schedulers, delay_receiver_ordering);
+criterion_main!(benches);
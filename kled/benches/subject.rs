@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use kled::cancellable::ArcCancellable;
+use kled::prelude::*;
+use kled::subject::PublishSubject;
+
+const ITEMS: usize = 1_000;
+
+fn publish_subject_fan_out(c: &mut Criterion) {
+    let mut group = c.benchmark_group("publish_subject_fan_out");
+    for observers in [1, 10, 100].iter() {
+        group.throughput(Throughput::Elements((ITEMS * observers) as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(observers),
+            observers,
+            |b, &observers| {
+                b.iter(|| {
+                    async_std::task::block_on(async {
+                        let mut subject = PublishSubject::<ArcCancellable, usize, ()>::default();
+                        for _ in 0..observers {
+                            subject
+                                .clone()
+                                .subscribe_next(|item| {
+                                    black_box(item);
+                                })
+                                .await;
+                        }
+                        for item in 0..ITEMS {
+                            subject.on_next(item).await;
+                        }
+                        subject.on_completed().await;
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, publish_subject_fan_out);
+criterion_main!(benches);
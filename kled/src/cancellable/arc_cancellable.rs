@@ -40,4 +40,9 @@ impl core::Cancellable for ArcCancellable {
     async fn cancel(&self) {
         self.cancelled.store(true, Ordering::Relaxed);
     }
+
+    fn try_cancel_now(&self) -> bool {
+        self.cancelled.store(true, Ordering::Relaxed);
+        true
+    }
 }
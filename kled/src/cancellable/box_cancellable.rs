@@ -0,0 +1,56 @@
+use crate::core;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[async_trait]
+trait DynCancellable: Send + Sync {
+    async fn cancel(&self);
+
+    fn try_cancel_now(&self) -> bool;
+}
+
+#[async_trait]
+impl<Cancellable> DynCancellable for Cancellable
+where
+    Cancellable: core::Cancellable + Send + Sync,
+{
+    async fn cancel(&self) {
+        core::Cancellable::cancel(self).await
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        core::Cancellable::try_cancel_now(self)
+    }
+}
+
+/// A type-erased [`Cancellable`].
+///
+/// [`Cancellable`]: ../core/trait.Cancellable.html
+#[derive(Clone)]
+pub struct BoxCancellable {
+    inner: Arc<Box<dyn DynCancellable>>,
+}
+
+impl BoxCancellable {
+    pub fn new<Cancellable>(cancellable: Cancellable) -> Self
+    where
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        let inner: Box<dyn DynCancellable> = Box::new(cancellable);
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+}
+
+#[async_trait]
+impl core::Cancellable for BoxCancellable {
+    async fn cancel(&self) {
+        self.inner.cancel().await
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        self.inner.try_cancel_now()
+    }
+}
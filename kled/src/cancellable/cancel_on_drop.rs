@@ -0,0 +1,119 @@
+use crate::core;
+
+/// A guard which cancels the wrapped [`Cancellable`] or [`Subscription`] when it is dropped.
+/// As [`Drop`] cannot await, the cancellation is performed asynchronously on the given
+/// [`Scheduler`].
+///
+/// [`Cancellable`]: ../core/trait.Cancellable.html
+/// [`Subscription`]: ../core/trait.Subscription.html
+/// [`Drop`]: https://doc.rust-lang.org/std/ops/trait.Drop.html
+/// [`Scheduler`]: ../core/trait.Scheduler.html
+#[must_use = "the wrapped cancellable is cancelled as soon as the guard is dropped"]
+pub struct CancelOnDrop<Inner, Scheduler> {
+    inner: Option<Inner>,
+    scheduler: Scheduler,
+    // Set by the constructor, as the guard may wrap either a Cancellable or a Subscription.
+    cancel_fn: fn(&Scheduler, Inner),
+}
+
+impl<Inner, Scheduler> CancelOnDrop<Inner, Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    /// Creates a guard which cancels `cancellable` once it is dropped.
+    pub fn new(cancellable: Inner, scheduler: Scheduler) -> Self
+    where
+        Inner: core::Cancellable + Send + Sync + 'static,
+    {
+        Self {
+            inner: Some(cancellable),
+            scheduler,
+            cancel_fn: |scheduler, cancellable| {
+                scheduler.schedule(async move { cancellable.cancel().await })
+            },
+        }
+    }
+
+    /// Creates a guard which cancels `subscription` once it is dropped.
+    pub fn from_subscription(subscription: Inner, scheduler: Scheduler) -> Self
+    where
+        Inner: core::Subscription + Send + Sync + 'static,
+    {
+        Self {
+            inner: Some(subscription),
+            scheduler,
+            cancel_fn: |scheduler, subscription| {
+                scheduler.schedule(async move { subscription.cancel().await })
+            },
+        }
+    }
+
+    /// Returns the wrapped cancellable or subscription.
+    pub fn get(&self) -> &Inner {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Disarms the guard and returns the wrapped cancellable or subscription without
+    /// cancelling it.
+    pub fn release(mut self) -> Inner {
+        self.inner.take().unwrap()
+    }
+}
+
+impl<Inner, Scheduler> Drop for CancelOnDrop<Inner, Scheduler> {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.take() {
+            (self.cancel_fn)(&self.scheduler, inner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancellable::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscription::*;
+
+    #[async_std::test]
+    async fn cancel_on_drop() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let stub = ArcCancellableStub::default();
+        let guard = CancelOnDrop::new(stub.cancellable(), scheduler.clone());
+        assert!(!stub.is_cancelled());
+        drop(guard);
+        scheduler.join();
+        assert!(stub.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn release() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let stub = ArcCancellableStub::default();
+        let guard = CancelOnDrop::new(stub.cancellable(), scheduler.clone());
+        let _cancellable = guard.release();
+        scheduler.join();
+        assert!(!stub.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn cancel_subscription_on_drop() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let stub = ArcSubscriptionStub::default();
+        let guard = CancelOnDrop::from_subscription(stub.subscription(), scheduler.clone());
+        assert!(!stub.is_cancelled());
+        drop(guard);
+        scheduler.join();
+        assert!(stub.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn release_subscription() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let stub = ArcSubscriptionStub::default();
+        let guard = CancelOnDrop::from_subscription(stub.subscription(), scheduler.clone());
+        let _subscription = guard.release();
+        scheduler.join();
+        assert!(!stub.is_cancelled());
+    }
+}
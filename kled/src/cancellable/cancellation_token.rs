@@ -0,0 +1,195 @@
+use crate::cancellable::CompositeCancellable;
+use crate::core;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+#[chronobreak]
+use std::sync::{Arc, Weak};
+
+/// A [`Cancellable`] which cancels a whole tree of subscriptions. Cancellables registered
+/// with a token and all of its children are cancelled when the token is cancelled, while
+/// cancelling a child leaves its parent untouched.
+///
+/// [`Cancellable`]: ../core/trait.Cancellable.html
+#[derive(Clone)]
+pub struct CancellationToken {
+    data: Arc<Data>,
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self {
+            data: Arc::new(Data::new(None)),
+        }
+    }
+}
+
+struct Data {
+    composite: CompositeCancellable,
+    children: Mutex<Children>,
+    // The parent and the key under which this token is registered with it.
+    parent: Option<(Weak<Data>, usize)>,
+}
+
+#[derive(Default)]
+struct Children {
+    cancelled: bool,
+    next_key: usize,
+    // The children are held strongly, so that the cancellables registered with a dropped
+    // child are still cancelled together with the current token.
+    tokens: HashMap<usize, Arc<Data>>,
+}
+
+impl Data {
+    fn new(parent: Option<(Weak<Data>, usize)>) -> Self {
+        Self {
+            composite: CompositeCancellable::default(),
+            children: Mutex::new(Children::default()),
+            parent,
+        }
+    }
+
+    fn deregister(&self) {
+        if let Some((parent, key)) = &self.parent {
+            if let Some(parent) = parent.upgrade() {
+                parent.children.lock().tokens.remove(key);
+            }
+        }
+    }
+}
+
+impl CancellationToken {
+    /// Returns a new token which is cancelled together with the current token. The child is
+    /// deregistered from the current token once it is cancelled.
+    pub async fn child(&self) -> Self {
+        let (child, cancelled) = {
+            let mut children = self.data.children.lock();
+            let key = children.next_key;
+            children.next_key += 1;
+            let parent = Some((Arc::downgrade(&self.data), key));
+            let child = Self {
+                data: Arc::new(Data::new(parent)),
+            };
+            if !children.cancelled {
+                children.tokens.insert(key, child.data.clone());
+            }
+            (child, children.cancelled)
+        };
+        if cancelled {
+            child.cancel().await;
+        }
+        child
+    }
+
+    /// Registers `cancellable` to be cancelled together with the current token. If the token
+    /// has already been cancelled, `cancellable` is cancelled immediately.
+    pub async fn register<Cancellable>(&self, cancellable: Cancellable)
+    where
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        self.data.composite.add(cancellable).await;
+    }
+
+    pub async fn is_cancelled(&self) -> bool {
+        self.data.composite.is_cancelled().await
+    }
+
+    /// Returns the number of children which have not been cancelled yet.
+    pub fn child_count(&self) -> usize {
+        self.data.children.lock().tokens.len()
+    }
+}
+
+#[async_trait]
+impl core::Cancellable for CancellationToken {
+    async fn cancel(&self) {
+        self.data.deregister();
+        let children = {
+            let mut children = self.data.children.lock();
+            children.cancelled = true;
+            std::mem::take(&mut children.tokens)
+        };
+        self.data.composite.cancel().await;
+        for (_, child) in children {
+            CancellationToken { data: child }.cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancellable::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn cancel_tree() {
+        let stub1 = ArcCancellableStub::default();
+        let stub2 = ArcCancellableStub::default();
+        let token = CancellationToken::default();
+        let child = token.child().await;
+        token.register(stub1.cancellable()).await;
+        child.register(stub2.cancellable()).await;
+        token.cancel().await;
+        assert!(child.is_cancelled().await);
+        assert!(stub1.is_cancelled());
+        assert!(stub2.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn cancel_child() {
+        let stub1 = ArcCancellableStub::default();
+        let stub2 = ArcCancellableStub::default();
+        let token = CancellationToken::default();
+        let child = token.child().await;
+        token.register(stub1.cancellable()).await;
+        child.register(stub2.cancellable()).await;
+        child.cancel().await;
+        assert!(!token.is_cancelled().await);
+        assert!(!stub1.is_cancelled());
+        assert!(stub2.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn register_lazy_cancellable() {
+        let test_observable = observable::TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let token = CancellationToken::default();
+        let cancellable = test_observable.clone().subscribe_next(|_| {}).await;
+        token.register(cancellable).await;
+        token.cancel().await;
+        assert!(test_observable.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn deregister_cancelled_child() {
+        let token = CancellationToken::default();
+        let child = token.child().await;
+        assert_eq!(token.child_count(), 1);
+        child.cancel().await;
+        assert_eq!(token.child_count(), 0);
+        assert!(!token.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn cancel_dropped_child() {
+        let stub = ArcCancellableStub::default();
+        let token = CancellationToken::default();
+        let child = token.child().await;
+        child.register(stub.cancellable()).await;
+        drop(child);
+        assert_eq!(token.child_count(), 1);
+        token.cancel().await;
+        assert!(stub.is_cancelled());
+        assert_eq!(token.child_count(), 0);
+    }
+
+    #[async_std::test]
+    async fn child_after_cancel() {
+        let token = CancellationToken::default();
+        token.cancel().await;
+        let child = token.child().await;
+        assert!(child.is_cancelled().await);
+        assert_eq!(token.child_count(), 0);
+    }
+}
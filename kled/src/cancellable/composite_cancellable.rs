@@ -0,0 +1,115 @@
+use crate::cancellable::BoxCancellable;
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+/// A [`Cancellable`] which groups any number of cancellables and cancels all of them at once.
+/// Cancellables added after the group has been cancelled are cancelled immediately.
+///
+/// [`Cancellable`]: ../core/trait.Cancellable.html
+#[derive(Clone)]
+pub struct CompositeCancellable {
+    data: Arc<Mutex<Data>>,
+}
+
+impl Default for CompositeCancellable {
+    fn default() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data::default())),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Data {
+    cancelled: bool,
+    cancellables: Vec<BoxCancellable>,
+}
+
+impl CompositeCancellable {
+    pub async fn add<Cancellable>(&self, cancellable: Cancellable)
+    where
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        let mut data = self.data.lock().await;
+        if data.cancelled {
+            drop(data);
+            cancellable.cancel().await;
+        } else {
+            data.cancellables.push(BoxCancellable::new(cancellable));
+        }
+    }
+
+    /// Removes all cancellables from the group without cancelling them.
+    pub async fn clear(&self) {
+        self.data.lock().await.cancellables.clear();
+    }
+
+    pub async fn is_cancelled(&self) -> bool {
+        self.data.lock().await.cancelled
+    }
+
+    pub async fn len(&self) -> usize {
+        self.data.lock().await.cancellables.len()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+#[async_trait]
+impl core::Cancellable for CompositeCancellable {
+    async fn cancel(&self) {
+        let cancellables = {
+            let mut data = self.data.lock().await;
+            data.cancelled = true;
+            std::mem::take(&mut data.cancellables)
+        };
+        for cancellable in cancellables {
+            cancellable.cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancellable::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn cancel() {
+        let stub1 = ArcCancellableStub::default();
+        let stub2 = ArcCancellableStub::default();
+        let composite = CompositeCancellable::default();
+        composite.add(stub1.cancellable()).await;
+        composite.add(stub2.cancellable()).await;
+        assert_eq!(composite.len().await, 2);
+        composite.cancel().await;
+        assert!(composite.is_cancelled().await);
+        assert!(stub1.is_cancelled());
+        assert!(stub2.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn add_after_cancel() {
+        let stub = ArcCancellableStub::default();
+        let composite = CompositeCancellable::default();
+        composite.cancel().await;
+        composite.add(stub.cancellable()).await;
+        assert!(stub.is_cancelled());
+        assert!(composite.is_empty().await);
+    }
+
+    #[async_std::test]
+    async fn clear() {
+        let stub = ArcCancellableStub::default();
+        let composite = CompositeCancellable::default();
+        composite.add(stub.cancellable()).await;
+        composite.clear().await;
+        composite.cancel().await;
+        assert!(!stub.is_cancelled());
+    }
+}
@@ -1,4 +1,9 @@
 reexport_all! {
     mod arc_cancellable;
+    mod box_cancellable;
+    mod cancel_on_drop;
+    mod cancellation_token;
+    mod composite_cancellable;
     mod lazy_cancellable;
+    mod serial_cancellable;
 }
@@ -0,0 +1,97 @@
+use crate::cancellable::BoxCancellable;
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+/// A [`Cancellable`] which holds at most one cancellable at a time. Setting a new cancellable
+/// cancels the previous one, and cancellables set after the `SerialCancellable` has been
+/// cancelled are cancelled immediately.
+///
+/// [`Cancellable`]: ../core/trait.Cancellable.html
+#[derive(Clone)]
+pub struct SerialCancellable {
+    data: Arc<Mutex<Data>>,
+}
+
+impl Default for SerialCancellable {
+    fn default() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data::default())),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Data {
+    cancelled: bool,
+    cancellable: Option<BoxCancellable>,
+}
+
+impl SerialCancellable {
+    /// Replaces the current cancellable and cancels the previous one.
+    pub async fn set<Cancellable>(&self, cancellable: Cancellable)
+    where
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        let mut data = self.data.lock().await;
+        if data.cancelled {
+            drop(data);
+            cancellable.cancel().await;
+            return;
+        }
+        let previous = data.cancellable.replace(BoxCancellable::new(cancellable));
+        drop(data);
+        if let Some(previous) = previous {
+            previous.cancel().await;
+        }
+    }
+
+    pub async fn is_cancelled(&self) -> bool {
+        self.data.lock().await.cancelled
+    }
+}
+
+#[async_trait]
+impl core::Cancellable for SerialCancellable {
+    async fn cancel(&self) {
+        let cancellable = {
+            let mut data = self.data.lock().await;
+            data.cancelled = true;
+            data.cancellable.take()
+        };
+        if let Some(cancellable) = cancellable {
+            cancellable.cancel().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancellable::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn set_cancels_previous() {
+        let stub1 = ArcCancellableStub::default();
+        let stub2 = ArcCancellableStub::default();
+        let serial = SerialCancellable::default();
+        serial.set(stub1.cancellable()).await;
+        serial.set(stub2.cancellable()).await;
+        assert!(stub1.is_cancelled());
+        assert!(!stub2.is_cancelled());
+        serial.cancel().await;
+        assert!(serial.is_cancelled().await);
+        assert!(stub2.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn set_after_cancel() {
+        let stub = ArcCancellableStub::default();
+        let serial = SerialCancellable::default();
+        serial.cancel().await;
+        serial.set(stub.cancellable()).await;
+        assert!(stub.is_cancelled());
+    }
+}
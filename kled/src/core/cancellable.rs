@@ -9,6 +9,14 @@ pub trait Cancellable: Clone {
     /// the observable stops emitting in a timely fashion, it may emit further
     /// signals after the call to cancel.
     async fn cancel(&self);
+
+    /// Cancels the observable without awaiting if the implementation supports it, which
+    /// avoids boxing a future. Returns `false` if [`cancel`] must be awaited instead.
+    ///
+    /// [`cancel`]: trait.Cancellable.html#tymethod.cancel
+    fn try_cancel_now(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait]
@@ -1,7 +1,10 @@
-use crate::{core, flow, subscriber};
+use crate::{core, flow, hooks, parallel, subscriber};
+use crate::cancellable::CancelOnDrop;
 use crate::flow::operators::*;
+use crate::flow::BoxFlow;
 use crate::subscription::LazySubscription;
 use async_trait::async_trait;
+use futures::Future;
 
 /// A backpressured source of `Item`s to which a [`Subscriber`] may subscribe.
 ///
@@ -24,6 +27,26 @@ where
     where
         Subscriber: core::Subscriber<Subscription, Item, Error> + Send + 'static;
 
+    /// Subscribes the given [`RawSubscriber`] to the current `Flow`. Sources and operators
+    /// which support raw subscribers override this to avoid allocating a future per signal,
+    /// otherwise the raw subscriber is adapted via [`RawAsSubscriber`].
+    ///
+    /// [`RawSubscriber`]: trait.RawSubscriber.html
+    /// [`RawAsSubscriber`]: ../subscriber/struct.RawAsSubscriber.html
+    async fn subscribe_raw<Subscriber>(self, subscriber: Subscriber)
+    where
+        Self: Sized,
+        Subscriber: core::RawSubscriber<Subscription, Item, Error> + Send + 'static,
+    {
+        self.subscribe(subscriber::RawAsSubscriber::new(subscriber)).await
+    }
+
+    /// Subscribes to the current `Flow` with a subscriber calling `next_fn` for each item.
+    /// An error is passed to [`hooks::on_undeliverable_error`]. The subscriber is wrapped in a
+    /// [`SafeSubscriber`].
+    ///
+    /// [`hooks::on_undeliverable_error`]: ../hooks/fn.on_undeliverable_error.html
+    /// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
     async fn subscribe_next<NextFn>(self, next_fn: NextFn) -> LazySubscription<Subscription>
     where
         Self: Sized,
@@ -31,16 +54,21 @@ where
     {
         let subscriber = subscriber::LambdaSubscriber::new(
             next_fn,
-            |_| {
-                panic! {}
-            },
+            hooks::on_undeliverable_error::<flow::Error<Error>>,
             || {},
         );
         let subscription = subscriber.subscription();
-        self.subscribe(subscriber).await;
+        let demand = subscriber.demand();
+        self.subscribe(subscriber::SafeSubscriber::with_demand(subscriber, demand))
+            .await;
         subscription
     }
 
+    /// Subscribes to the current `Flow` with a subscriber calling `next_fn`, `error_fn` and
+    /// `complete_fn` for the respective signals. The subscriber is wrapped in a
+    /// [`SafeSubscriber`].
+    ///
+    /// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
     async fn subscribe_all<NextFn, ErrorFn, CompletedFn>(
         self,
         next_fn: NextFn,
@@ -55,19 +83,235 @@ where
     {
         let subscriber = subscriber::LambdaSubscriber::new(next_fn, error_fn, complete_fn);
         let subscription = subscriber.subscription();
-        self.subscribe(subscriber).await;
+        let demand = subscriber.demand();
+        self.subscribe(subscriber::SafeSubscriber::with_demand(subscriber, demand))
+            .await;
         subscription
     }
 
+    async fn subscribe_scoped<NextFn, Scheduler>(
+        self,
+        next_fn: NextFn,
+        scheduler: Scheduler,
+    ) -> CancelOnDrop<LazySubscription<Subscription>, Scheduler>
+    where
+        Self: Sized,
+        NextFn: FnMut(Item) + Send + 'static,
+        Scheduler: core::Scheduler,
+    {
+        CancelOnDrop::from_subscription(self.subscribe_next(next_fn).await, scheduler)
+    }
+
+    async fn subscribe_all_scoped<NextFn, ErrorFn, CompletedFn, Scheduler>(
+        self,
+        next_fn: NextFn,
+        error_fn: ErrorFn,
+        complete_fn: CompletedFn,
+        scheduler: Scheduler,
+    ) -> CancelOnDrop<LazySubscription<Subscription>, Scheduler>
+    where
+        Self: Sized,
+        NextFn: FnMut(Item) + Send + 'static,
+        ErrorFn: FnMut(flow::Error<Error>) + Send + 'static,
+        CompletedFn: FnMut() + Send + 'static,
+        Scheduler: core::Scheduler,
+    {
+        let subscription = self.subscribe_all(next_fn, error_fn, complete_fn).await;
+        CancelOnDrop::from_subscription(subscription, scheduler)
+    }
+
+    /// Subscribes to the current `Flow` and awaits the future returned by `for_each_fn` for
+    /// each item. Up to `concurrency` futures are awaited at once, see [`map_async`].
+    ///
+    /// [`map_async`]: trait.Flow.html#method.map_async
+    async fn for_each_async<ForEachFn, Fut>(
+        self,
+        for_each_fn: ForEachFn,
+        concurrency: usize,
+    ) -> LazySubscription<DemandSubscription<Subscription>>
+    where
+        Self: Sized,
+        ForEachFn: FnMut(Item) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.map_async(for_each_fn, concurrency)
+            .subscribe_next(|()| {})
+            .await
+    }
+
+    /// Returns a [`BoxFlow`] which erases the type of the current `Flow` and its
+    /// `Subscription`.
+    ///
+    /// [`BoxFlow`]: ../flow/struct.BoxFlow.html
+    fn boxed(self) -> BoxFlow<Item, Error>
+    where
+        Self: Sized + Send + 'static,
+    {
+        BoxFlow::new(self)
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn dematerialize(
         self,
     ) -> Dematerialize<Self, Subscription, Item, Error>
     where
         Self: Sized,
     {
-        Dematerialize::new(self)
+        hooks::on_assembly("dematerialize", Dematerialize::new(self))
+    }
+
+    /// Returns a [`Flow`] that calls `finally_fn` exactly once after the current `Flow`
+    /// terminated with an error, completed or was cancelled by the downstream.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_finally<FinallyFn>(
+        self,
+        finally_fn: FinallyFn,
+    ) -> DoFinally<Self, Subscription, Item, Error, FinallyFn>
+    where
+        Self: Sized,
+        FinallyFn: FnOnce() + Send + 'static,
+    {
+        hooks::on_assembly("do_finally", DoFinally::new(self, finally_fn))
+    }
+
+    /// Returns a [`Flow`] that calls `cancel_fn` whenever the downstream cancels its
+    /// subscription, before the cancellation is forwarded upstream.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_cancel<CancelFn>(
+        self,
+        cancel_fn: CancelFn,
+    ) -> DoOnCancel<Self, Subscription, Item, Error, CancelFn>
+    where
+        Self: Sized,
+        CancelFn: FnMut() + Send + 'static,
+    {
+        hooks::on_assembly("do_on_cancel", DoOnCancel::new(self, cancel_fn))
+    }
+
+    /// Returns a [`Flow`] that calls `completed_fn` before forwarding the completion of the
+    /// current `Flow`.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_completed<CompletedFn>(
+        self,
+        completed_fn: CompletedFn,
+    ) -> DoOnCompleted<Self, Subscription, Item, Error, CompletedFn>
+    where
+        Self: Sized,
+        CompletedFn: FnMut() + Send + 'static,
+    {
+        hooks::on_assembly("do_on_completed", DoOnCompleted::new(self, completed_fn))
+    }
+
+    /// Returns a [`Flow`] that calls `error_fn` with a reference to the error emitted by the
+    /// current `Flow` before forwarding it.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_error<ErrorFn>(
+        self,
+        error_fn: ErrorFn,
+    ) -> DoOnError<Self, Subscription, Item, Error, ErrorFn>
+    where
+        Self: Sized,
+        ErrorFn: FnMut(&flow::Error<Error>) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_error", DoOnError::new(self, error_fn))
+    }
+
+    /// Returns a [`Flow`] that calls `next_fn` with a reference to each item emitted by the
+    /// current `Flow` before forwarding it.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_next<NextFn>(
+        self,
+        next_fn: NextFn,
+    ) -> DoOnNext<Self, Subscription, Item, Error, NextFn>
+    where
+        Self: Sized,
+        NextFn: FnMut(&Item) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_next", DoOnNext::new(self, next_fn))
     }
 
+    /// Returns a [`Flow`] that calls `request_fn` with `n` each time the downstream calls
+    /// [`Subscription::request`] with `n`, before the request is forwarded upstream.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    /// [`Subscription::request`]: trait.Subscription.html#tymethod.request
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_request<RequestFn>(
+        self,
+        request_fn: RequestFn,
+    ) -> DoOnRequest<Self, Subscription, Item, Error, RequestFn>
+    where
+        Self: Sized,
+        RequestFn: FnMut(usize) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_request", DoOnRequest::new(self, request_fn))
+    }
+
+    /// Returns a [`Flow`] that calls `subscribe_fn` with a reference to the subscription
+    /// before it is passed to the downstream [`Subscriber`].
+    ///
+    /// [`Flow`]: trait.Flow.html
+    /// [`Subscriber`]: trait.Subscriber.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_subscribe<SubscribeFn>(
+        self,
+        subscribe_fn: SubscribeFn,
+    ) -> DoOnSubscribe<Self, Subscription, Item, Error, SubscribeFn>
+    where
+        Self: Sized,
+        SubscribeFn: FnMut(&Subscription) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_subscribe", DoOnSubscribe::new(self, subscribe_fn))
+    }
+
+    /// Returns a `Flow` that only emits those items of the current `Flow` for which the
+    /// future returned by `predicate` resolves to `true`. Up to `concurrency` of those futures
+    /// are awaited at once, while the order of the items is preserved.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn filter_async<Fut, Predicate>(
+        self,
+        predicate: Predicate,
+        concurrency: usize,
+    ) -> FilterAsync<Self, Subscription, Item, Error, Fut, Predicate>
+    where
+        Self: Sized,
+        Fut: Future<Output = bool> + Send + 'static,
+        Predicate: FnMut(&Item) -> Fut + Send + 'static,
+    {
+        hooks::on_assembly("filter_async", FilterAsync::new(self, predicate, concurrency))
+    }
+
+    /// Returns a `Flow` that requests `high_tide` items from the current `Flow` upfront and
+    /// requests `low_tide` more items each time `low_tide` items have been emitted to the
+    /// downstream. Items are buffered until they are requested by the downstream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low_tide` is zero or greater than `high_tide`.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn limit_rate(
+        self,
+        high_tide: usize,
+        low_tide: usize,
+    ) -> LimitRate<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        assert!(low_tide > 0 && low_tide <= high_tide);
+        hooks::on_assembly("limit_rate", LimitRate::new(self, high_tide, low_tide))
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn map<ItemOut, UnaryOp>(
         self,
         unary_op: UnaryOp,
@@ -76,29 +320,84 @@ where
         Self: Sized,
         UnaryOp: FnMut(Item) -> ItemOut + Send + 'static,
     {
-        Map::new(self, unary_op)
+        hooks::on_assembly("map", Map::new(self, unary_op))
+    }
+
+    /// Returns a `Flow` that emits the results of the futures returned by `unary_op` for each
+    /// item of the current `Flow`. With a `concurrency` of 1, each future is awaited before
+    /// the next item is received. Otherwise up to `concurrency` futures are awaited at once,
+    /// while their results are still emitted in the order of the items. A `concurrency` of 0
+    /// is treated as 1.
+    ///
+    /// Items are only held back while further items have been requested from the current
+    /// `Flow`, so that the downstream demand is always fulfilled.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn map_async<ItemOut, Fut, UnaryOp>(
+        self,
+        unary_op: UnaryOp,
+        concurrency: usize,
+    ) -> MapAsync<Self, Subscription, Item, Error, ItemOut, Fut, UnaryOp>
+    where
+        Self: Sized,
+        ItemOut: Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+    {
+        hooks::on_assembly("map_async", MapAsync::new(self, unary_op, concurrency))
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn materialize(
         self,
     ) -> Materialize<Self, Subscription, Item, Error>
     where
         Self: Sized,
     {
-        Materialize::new(self)
+        hooks::on_assembly("materialize", Materialize::new(self))
+    }
+
+    /// Returns a `Flow` that reports the counters `items`, `errors`, `completions`,
+    /// `cancellations` and `requested` with the given `label` to the [`Metrics`] set via
+    /// [`metrics::set_metrics`].
+    ///
+    /// [`Metrics`]: ../metrics/trait.Metrics.html
+    /// [`metrics::set_metrics`]: ../metrics/fn.set_metrics.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn metered(self, label: &'static str) -> Metered<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("metered", Metered::new(self, label))
     }
 
+    /// Returns a `Flow` that performs the current `Flow`'s emissions on the specified
+    /// [`Scheduler`]. Initially, `prefetch` items are requested from the current `Flow` and
+    /// buffered in a bounded queue. Each time 75% of `prefetch` items have been emitted, the
+    /// same amount is requested again. If `delay_error` is `false`, an error is emitted as
+    /// soon as it is received and the queued items are dropped. Otherwise, all queued items
+    /// are emitted before the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefetch` is zero.
+    ///
+    /// [`Scheduler`]: trait.Scheduler.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn observe_on<Scheduler>(
         self,
         scheduler: Scheduler,
+        prefetch: usize,
+        delay_error: bool,
     ) -> ObserveOn<Self, Subscription, Item, Error, Scheduler>
     where
         Self: Sized,
         Scheduler: core::Scheduler + Send + 'static,
     {
-        Dematerialize::new(ObserveOnRaw::new(Materialize::new(self), scheduler))
+        assert!(prefetch > 0);
+        hooks::on_assembly("observe_on", ObserveOn::new(self, scheduler, prefetch, delay_error))
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn on_backpressure_buffer(
         self,
         buffer_strategy: flow::BufferStrategy,
@@ -106,9 +405,13 @@ where
     where
         Self: Sized,
     {
-        OnBackpressureBuffer::new(self, buffer_strategy, flow::default_buffer_capacity())
+        hooks::on_assembly(
+            "on_backpressure_buffer",
+            OnBackpressureBuffer::new(self, flow::BufferConfig::new(buffer_strategy)),
+        )
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn on_backpressure_buffer_with_capacity(
         self,
         buffer_strategy: flow::BufferStrategy,
@@ -117,30 +420,87 @@ where
     where
         Self: Sized,
     {
-        OnBackpressureBuffer::new(self, buffer_strategy, capacity)
+        hooks::on_assembly(
+            "on_backpressure_buffer_with_capacity",
+            OnBackpressureBuffer::new(
+                self,
+                flow::BufferConfig::new(buffer_strategy).capacity(capacity),
+            ),
+        )
+    }
+
+    /// Returns a `Flow` that buffers items without outstanding demand as configured by
+    /// `config`, see [`BufferConfig`].
+    ///
+    /// [`BufferConfig`]: ../flow/struct.BufferConfig.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn on_backpressure_buffer_with(
+        self,
+        config: flow::BufferConfig<Item>,
+    ) -> OnBackpressureBuffer<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("on_backpressure_buffer_with", OnBackpressureBuffer::new(self, config))
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn on_backpressure_drop(self) -> OnBackpressureDrop<Self, Subscription, Item, Error>
     where
         Self: Sized,
     {
-        OnBackpressureDrop::new(self)
+        hooks::on_assembly("on_backpressure_drop", OnBackpressureDrop::new(self))
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn on_backpressure_error(self) -> OnBackpressureError<Self, Subscription, Item, Error>
     where
         Self: Sized,
     {
-        OnBackpressureError::new(self)
+        hooks::on_assembly("on_backpressure_error", OnBackpressureError::new(self))
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn on_backpressure_latest(self) -> OnBackpressureLatest<Self, Subscription, Item, Error>
     where
         Self: Sized,
     {
-        OnBackpressureLatest::new(self)
+        hooks::on_assembly("on_backpressure_latest", OnBackpressureLatest::new(self))
+    }
+
+    /// Returns a [`ParallelFlow`] which distributes the items of the current `Flow` over
+    /// `rails` rails in round-robin order. Items are only requested from the current `Flow`
+    /// once the rail they will be assigned to has requested them. Errors are passed to all
+    /// rails. A `rails` count of zero is treated as one.
+    ///
+    /// [`ParallelFlow`]: trait.ParallelFlow.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn parallel(self, rails: usize) -> parallel::FromFlow<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+        Error: Clone,
+    {
+        hooks::on_assembly("parallel", parallel::FromFlow::new(self, rails.max(1)))
     }
 
+    /// Returns a `Flow` that requests items from the current `Flow` in batches of `count`
+    /// and replenishes once 75% of a batch have been emitted, see [`limit_rate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    ///
+    /// [`limit_rate`]: #method.limit_rate
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn rebatch_requests(self, count: usize) -> LimitRate<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        assert!(count > 0, "rebatch_requests: count must be positive");
+        self.limit_rate(count, count - count / 4)
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn scan<ItemOut, BinaryOp>(
         self,
         initial_value: ItemOut,
@@ -151,9 +511,29 @@ where
         ItemOut: Clone + Send + 'static,
         BinaryOp: FnMut(ItemOut, Item) -> ItemOut + Send + 'static,
     {
-        Scan::new(self, initial_value, binary_op)
+        hooks::on_assembly("scan", Scan::new(self, initial_value, binary_op))
+    }
+
+    /// Like [`scan`], but `binary_op` returns a future which is awaited before the next item
+    /// is received.
+    ///
+    /// [`scan`]: trait.Flow.html#method.scan
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn scan_async<ItemOut, Fut, BinaryOp>(
+        self,
+        initial_value: ItemOut,
+        binary_op: BinaryOp,
+    ) -> ScanAsync<Self, Subscription, Item, Error, ItemOut, Fut, BinaryOp>
+    where
+        Self: Sized,
+        ItemOut: Clone + Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        BinaryOp: FnMut(ItemOut, Item) -> Fut + Send + 'static,
+    {
+        hooks::on_assembly("scan_async", ScanAsync::new(self, initial_value, binary_op))
     }
 
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn subscribe_on<Scheduler>(
         self,
         scheduler: Scheduler,
@@ -162,6 +542,62 @@ where
         Self: Sized,
         Scheduler: core::Scheduler + Send + 'static,
     {
-        SubscribeOn::new(self, scheduler)
+        hooks::on_assembly("subscribe_on", SubscribeOn::new(self, scheduler))
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn tap<NextFn, ErrorFn, CompletedFn>(
+        self,
+        next_fn: NextFn,
+        error_fn: ErrorFn,
+        completed_fn: CompletedFn,
+    ) -> Tap<Self, Subscription, Item, Error, NextFn, ErrorFn, CompletedFn>
+    where
+        Self: Sized,
+        NextFn: FnMut(&Item) + Send + 'static,
+        ErrorFn: FnMut(&flow::Error<Error>) + Send + 'static,
+        CompletedFn: FnMut() + Send + 'static,
+    {
+        hooks::on_assembly("tap", Tap::new(self, next_fn, error_fn, completed_fn))
+    }
+
+    /// Converts the current `Flow` into an [`Observable`] that requests all items upon
+    /// subscription. Cancelling the observable cancels the subscription of the `Flow`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn to_observable(self) -> ToObservable<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("to_observable", ToObservable::new(self))
+    }
+
+    /// Returns a `Flow` that opens a `tracing` span named `flow` with the field `name` on
+    /// subscription and records an event within it for each signal of the current `Flow`
+    /// and for [`Subscription::cancel`] and [`Subscription::request`]. Requires the `tracing`
+    /// feature.
+    ///
+    /// [`Subscription::cancel`]: trait.Subscription.html#tymethod.cancel
+    /// [`Subscription::request`]: trait.Subscription.html#tymethod.request
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn traced(self, name: &'static str) -> Traced<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("traced", Traced::new(self, name))
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn unsubscribe_on<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> UnsubscribeOn<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        hooks::on_assembly("unsubscribe_on", UnsubscribeOn::new(self, scheduler))
     }
 }
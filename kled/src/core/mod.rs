@@ -4,6 +4,9 @@ reexport_all! {
     mod into;
     mod observable;
     mod observer;
+    mod parallel_flow;
+    mod raw_observer;
+    mod raw_subscriber;
     mod scheduler;
     mod subject;
     mod subscriber;
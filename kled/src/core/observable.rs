@@ -1,7 +1,9 @@
-use crate::{core, observer};
-use crate::cancellable::LazyCancellable;
+use crate::{core, flow, hooks, observer};
+use crate::cancellable::{CancelOnDrop, LazyCancellable};
 use crate::observable::operators::*;
+use crate::observable::BoxObservable;
 use async_trait::async_trait;
+use futures::Future;
 
 /// A non-backpressured source of `Item`s to which an [`Observer`] may subscribe.
 ///
@@ -23,6 +25,26 @@ where
     where
         Observer: core::Observer<Cancellable, Item, Error> + Send + 'static;
 
+    /// Subscribes the given [`RawObserver`] to the current `Observable`. Sources and operators
+    /// which support raw observers override this to avoid allocating a future per signal,
+    /// otherwise the raw observer is adapted via [`RawAsObserver`].
+    ///
+    /// [`RawObserver`]: trait.RawObserver.html
+    /// [`RawAsObserver`]: ../observer/struct.RawAsObserver.html
+    async fn subscribe_raw<Observer>(self, observer: Observer)
+    where
+        Self: Sized,
+        Observer: core::RawObserver<Cancellable, Item, Error> + Send + 'static,
+    {
+        self.subscribe(observer::RawAsObserver::new(observer)).await
+    }
+
+    /// Subscribes to the current `Observable` with an observer calling `next_fn` for each item.
+    /// An error is passed to [`hooks::on_undeliverable_error`]. The observer is wrapped in a
+    /// [`SafeObserver`].
+    ///
+    /// [`hooks::on_undeliverable_error`]: ../hooks/fn.on_undeliverable_error.html
+    /// [`SafeObserver`]: ../observer/struct.SafeObserver.html
     async fn subscribe_next<NextFn>(self, next_fn: NextFn) -> LazyCancellable<Cancellable>
     where
         Self: Sized,
@@ -30,16 +52,19 @@ where
     {
         let observer = observer::LambdaObserver::new(
             next_fn,
-            |_| {
-                panic! {}
-            },
+            hooks::on_undeliverable_error::<Error>,
             || {},
         );
         let cancellable = observer.cancellable();
-        self.subscribe(observer).await;
+        self.subscribe(observer::SafeObserver::new(observer)).await;
         cancellable
     }
 
+    /// Subscribes to the current `Observable` with an observer calling `next_fn`, `error_fn`
+    /// and `complete_fn` for the respective signals. The observer is wrapped in a
+    /// [`SafeObserver`].
+    ///
+    /// [`SafeObserver`]: ../observer/struct.SafeObserver.html
     async fn subscribe_all<NextFn, ErrorFn, CompletedFn>(
         self,
         next_fn: NextFn,
@@ -54,23 +79,212 @@ where
     {
         let observer = observer::LambdaObserver::new(next_fn, error_fn, complete_fn);
         let cancellable = observer.cancellable();
-        self.subscribe(observer).await;
+        self.subscribe(observer::SafeObserver::new(observer)).await;
         cancellable
     }
 
+    /// Like [`subscribe_next`], but returns a [`CancelOnDrop`] guard which cancels the
+    /// subscription on the given [`Scheduler`] once it is dropped.
+    ///
+    /// [`subscribe_next`]: trait.Observable.html#method.subscribe_next
+    /// [`CancelOnDrop`]: ../cancellable/struct.CancelOnDrop.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    async fn subscribe_scoped<NextFn, Scheduler>(
+        self,
+        next_fn: NextFn,
+        scheduler: Scheduler,
+    ) -> CancelOnDrop<LazyCancellable<Cancellable>, Scheduler>
+    where
+        Self: Sized,
+        NextFn: FnMut(Item) + Send + 'static,
+        Scheduler: core::Scheduler,
+    {
+        CancelOnDrop::new(self.subscribe_next(next_fn).await, scheduler)
+    }
+
+    /// Like [`subscribe_all`], but returns a [`CancelOnDrop`] guard which cancels the
+    /// subscription on the given [`Scheduler`] once it is dropped.
+    ///
+    /// [`subscribe_all`]: trait.Observable.html#method.subscribe_all
+    /// [`CancelOnDrop`]: ../cancellable/struct.CancelOnDrop.html
+    /// [`Scheduler`]: trait.Scheduler.html
+    async fn subscribe_all_scoped<NextFn, ErrorFn, CompletedFn, Scheduler>(
+        self,
+        next_fn: NextFn,
+        error_fn: ErrorFn,
+        complete_fn: CompletedFn,
+        scheduler: Scheduler,
+    ) -> CancelOnDrop<LazyCancellable<Cancellable>, Scheduler>
+    where
+        Self: Sized,
+        NextFn: FnMut(Item) + Send + 'static,
+        ErrorFn: FnMut(Error) + Send + 'static,
+        CompletedFn: FnMut() + Send + 'static,
+        Scheduler: core::Scheduler,
+    {
+        let cancellable = self.subscribe_all(next_fn, error_fn, complete_fn).await;
+        CancelOnDrop::new(cancellable, scheduler)
+    }
+
+    /// Subscribes to the current `Observable` and awaits the future returned by `for_each_fn` for
+    /// each item. Up to `concurrency` futures are awaited at once, see [`map_async`].
+    ///
+    /// [`map_async`]: trait.Observable.html#method.map_async
+    async fn for_each_async<ForEachFn, Fut>(
+        self,
+        for_each_fn: ForEachFn,
+        concurrency: usize,
+    ) -> LazyCancellable<Cancellable>
+    where
+        Self: Sized,
+        ForEachFn: FnMut(Item) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.map_async(for_each_fn, concurrency)
+            .subscribe_next(|()| {})
+            .await
+    }
+
+    /// Returns a [`BoxObservable`] which erases the type of the current `Observable` and its
+    /// `Cancellable`.
+    ///
+    /// [`BoxObservable`]: ../observable/struct.BoxObservable.html
+    fn boxed(self) -> BoxObservable<Item, Error>
+    where
+        Self: Sized + Send + 'static,
+    {
+        BoxObservable::new(self)
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn dematerialize(
         self,
     ) -> Dematerialize<Self, Cancellable, Item, Error>
     where
         Self: Sized,
     {
-        Dematerialize::new(self)
+        hooks::on_assembly("dematerialize", Dematerialize::new(self))
+    }
+
+    /// Returns an [`Observable`] that calls `finally_fn` exactly once after the current
+    /// `Observable` terminated with an error, completed or was cancelled by the downstream.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_finally<FinallyFn>(
+        self,
+        finally_fn: FinallyFn,
+    ) -> DoFinally<Self, Cancellable, Item, Error, FinallyFn>
+    where
+        Self: Sized,
+        FinallyFn: FnOnce() + Send + 'static,
+    {
+        hooks::on_assembly("do_finally", DoFinally::new(self, finally_fn))
+    }
+
+    /// Returns an [`Observable`] that calls `cancel_fn` whenever the downstream cancels its
+    /// subscription, before the cancellation is forwarded upstream.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_cancel<CancelFn>(
+        self,
+        cancel_fn: CancelFn,
+    ) -> DoOnCancel<Self, Cancellable, Item, Error, CancelFn>
+    where
+        Self: Sized,
+        CancelFn: FnMut() + Send + 'static,
+    {
+        hooks::on_assembly("do_on_cancel", DoOnCancel::new(self, cancel_fn))
+    }
+
+    /// Returns an [`Observable`] that calls `completed_fn` before forwarding the completion
+    /// of the current `Observable`.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_completed<CompletedFn>(
+        self,
+        completed_fn: CompletedFn,
+    ) -> DoOnCompleted<Self, Cancellable, Item, Error, CompletedFn>
+    where
+        Self: Sized,
+        CompletedFn: FnMut() + Send + 'static,
+    {
+        hooks::on_assembly("do_on_completed", DoOnCompleted::new(self, completed_fn))
+    }
+
+    /// Returns an [`Observable`] that calls `error_fn` with a reference to the error emitted by
+    /// the current `Observable` before forwarding it.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_error<ErrorFn>(
+        self,
+        error_fn: ErrorFn,
+    ) -> DoOnError<Self, Cancellable, Item, Error, ErrorFn>
+    where
+        Self: Sized,
+        ErrorFn: FnMut(&Error) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_error", DoOnError::new(self, error_fn))
+    }
+
+    /// Returns an [`Observable`] that calls `next_fn` with a reference to each item emitted by
+    /// the current `Observable` before forwarding it.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_next<NextFn>(
+        self,
+        next_fn: NextFn,
+    ) -> DoOnNext<Self, Cancellable, Item, Error, NextFn>
+    where
+        Self: Sized,
+        NextFn: FnMut(&Item) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_next", DoOnNext::new(self, next_fn))
+    }
+
+    /// Returns an [`Observable`] that calls `subscribe_fn` with a reference to the cancellable
+    /// before it is passed to the downstream [`Observer`].
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Observer`]: trait.Observer.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn do_on_subscribe<SubscribeFn>(
+        self,
+        subscribe_fn: SubscribeFn,
+    ) -> DoOnSubscribe<Self, Cancellable, Item, Error, SubscribeFn>
+    where
+        Self: Sized,
+        SubscribeFn: FnMut(&Cancellable) + Send + 'static,
+    {
+        hooks::on_assembly("do_on_subscribe", DoOnSubscribe::new(self, subscribe_fn))
+    }
+
+    /// Returns an `Observable` that only emits those items of the current `Observable` for
+    /// which the future returned by `predicate` resolves to `true`. Up to `concurrency` of
+    /// those futures are awaited at once, while the order of the items is preserved.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn filter_async<Fut, Predicate>(
+        self,
+        predicate: Predicate,
+        concurrency: usize,
+    ) -> FilterAsync<Self, Cancellable, Item, Error, Fut, Predicate>
+    where
+        Self: Sized,
+        Fut: Future<Output = bool> + Send + 'static,
+        Predicate: FnMut(&Item) -> Fut + Send + 'static,
+    {
+        hooks::on_assembly("filter_async", FilterAsync::new(self, predicate, concurrency))
     }
 
     /// Returns an [`Observable`] that applies the function `unary_op` to each element of the
     /// current `Observable` and emits the results of those function calls.
     ///
     /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn map<ItemOut, UnaryOp>(
         self,
         unary_op: UnaryOp,
@@ -79,33 +293,72 @@ where
         Self: Sized,
         UnaryOp: FnMut(Item) -> ItemOut + Send + 'static,
     {
-        Map::new(self, unary_op)
+        hooks::on_assembly("map", Map::new(self, unary_op))
     }
 
+    /// Returns an `Observable` that emits the results of the futures returned by `unary_op` for
+    /// each item of the current `Observable`. With a `concurrency` of 1, each future is awaited
+    /// before the next item is received. Otherwise up to `concurrency` futures are awaited at
+    /// once, while their results are still emitted in the order of the items. A `concurrency`
+    /// of 0 is treated as 1.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn map_async<ItemOut, Fut, UnaryOp>(
+        self,
+        unary_op: UnaryOp,
+        concurrency: usize,
+    ) -> MapAsync<Self, Cancellable, Item, Error, ItemOut, Fut, UnaryOp>
+    where
+        Self: Sized,
+        ItemOut: Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        UnaryOp: FnMut(Item) -> Fut + Send + 'static,
+    {
+        hooks::on_assembly("map_async", MapAsync::new(self, unary_op, concurrency))
+    }
+
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn materialize(
         self,
     ) -> Materialize<Self, Cancellable, Item, Error>
     where
         Self: Sized,
     {
-        Materialize::new(self)
+        hooks::on_assembly("materialize", Materialize::new(self))
+    }
+
+    /// Returns an [`Observable`] that reports the counters `items`, `errors`, `completions`
+    /// and `cancellations` with the given `label` to the [`Metrics`] set via
+    /// [`metrics::set_metrics`].
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Metrics`]: ../metrics/trait.Metrics.html
+    /// [`metrics::set_metrics`]: ../metrics/fn.set_metrics.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn metered(self, label: &'static str) -> Metered<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("metered", Metered::new(self, label))
     }
 
     /// Returns an [`Observable`] that performs the current `Observable`'s emissions on the
-    /// specified [`Scheduler`]. Note that `onError` notifications will not be sent in order
-    /// i.e. not all items sent before the error may be re-emitted on the scheduler.
+    /// specified [`Scheduler`]. If `delay_error` is `false`, an error is emitted as soon as it
+    /// is received and items which have not been emitted yet are dropped. Otherwise, all
+    /// items received before the error are emitted first.
     ///
     /// [`Observable`]: trait.Observable.html
     /// [`Scheduler`]: trait.Scheduler.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn observe_on<Scheduler>(
         self,
         scheduler: Scheduler,
+        delay_error: bool,
     ) -> ObserveOn<Self, Cancellable, Item, Error, Scheduler>
     where
         Self: Sized,
         Scheduler: core::Scheduler + Send + 'static,
     {
-        Dematerialize::new(ObserveOnRaw::new(Materialize::new(self), scheduler))
+        hooks::on_assembly("observe_on", ObserveOn::new(self, scheduler, delay_error))
     }
 
     /// Returns an [`Observable`] that first emits the provided `initial_value` as an item and the
@@ -114,6 +367,7 @@ where
     /// the current `Observable`.
     ///
     /// [`Observable`]: trait.Observable.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn scan<ItemOut, BinaryOp>(
         self,
         initial_value: ItemOut,
@@ -124,7 +378,26 @@ where
         ItemOut: Clone + Send + 'static,
         BinaryOp: FnMut(ItemOut, Item) -> ItemOut + Send + 'static,
     {
-        Scan::new(self, initial_value, binary_op)
+        hooks::on_assembly("scan", Scan::new(self, initial_value, binary_op))
+    }
+
+    /// Like [`scan`], but `binary_op` returns a future which is awaited before the next item
+    /// is received.
+    ///
+    /// [`scan`]: trait.Observable.html#method.scan
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn scan_async<ItemOut, Fut, BinaryOp>(
+        self,
+        initial_value: ItemOut,
+        binary_op: BinaryOp,
+    ) -> ScanAsync<Self, Cancellable, Item, Error, ItemOut, Fut, BinaryOp>
+    where
+        Self: Sized,
+        ItemOut: Clone + Send + 'static,
+        Fut: Future<Output = ItemOut> + Send + 'static,
+        BinaryOp: FnMut(ItemOut, Item) -> Fut + Send + 'static,
+    {
+        hooks::on_assembly("scan_async", ScanAsync::new(self, initial_value, binary_op))
     }
 
     /// Asynchronously subscribes [`Observer`]s to the current [`Observable`] on the given
@@ -133,6 +406,7 @@ where
     /// [`Observable`]: trait.Observable.html
     /// [`Observer`]: trait.Observer.html
     /// [`Scheduler`]: trait.Scheduler.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
     fn subscribe_on<Scheduler>(
         self,
         scheduler: Scheduler,
@@ -141,6 +415,80 @@ where
         Self: Sized,
         Scheduler: core::Scheduler + Send + 'static,
     {
-        SubscribeOn::new(self, scheduler)
+        hooks::on_assembly("subscribe_on", SubscribeOn::new(self, scheduler))
+    }
+
+    /// Returns an [`Observable`] that calls `next_fn`, `error_fn` and `completed_fn` for the
+    /// respective signals of the current `Observable` before forwarding them. This is useful
+    /// for debugging without having to implement an [`Observer`].
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Observer`]: trait.Observer.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn tap<NextFn, ErrorFn, CompletedFn>(
+        self,
+        next_fn: NextFn,
+        error_fn: ErrorFn,
+        completed_fn: CompletedFn,
+    ) -> Tap<Self, Cancellable, Item, Error, NextFn, ErrorFn, CompletedFn>
+    where
+        Self: Sized,
+        NextFn: FnMut(&Item) + Send + 'static,
+        ErrorFn: FnMut(&Error) + Send + 'static,
+        CompletedFn: FnMut() + Send + 'static,
+    {
+        hooks::on_assembly("tap", Tap::new(self, next_fn, error_fn, completed_fn))
+    }
+
+    /// Converts the current `Observable` into a [`Flow`] which handles items emitted without
+    /// outstanding demand according to `backpressure_strategy`. Errors are emitted as
+    /// [`flow::Error::Upstream`].
+    ///
+    /// [`Flow`]: trait.Flow.html
+    /// [`flow::Error::Upstream`]: ../flow/enum.Error.html#variant.Upstream
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn to_flow(
+        self,
+        backpressure_strategy: flow::BackpressureStrategy,
+    ) -> ToFlow<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("to_flow", ToFlow::new(self, backpressure_strategy))
+    }
+
+    /// Returns an [`Observable`] that opens a `tracing` span named `observable` with the field
+    /// `name` on subscription and records an event within it for each signal of the current
+    /// `Observable` and for [`Cancellable::cancel`]. Requires the `tracing` feature.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Cancellable::cancel`]: trait.Cancellable.html#tymethod.cancel
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn traced(self, name: &'static str) -> Traced<Self, Cancellable, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly("traced", Traced::new(self, name))
+    }
+
+    /// Returns an [`Observable`] whose [`Cancellable`] cancels the current `Observable` on the
+    /// given [`Scheduler`] instead of the task calling [`Cancellable::cancel`]. This is useful
+    /// if cancelling the current `Observable` is expensive.
+    ///
+    /// [`Observable`]: trait.Observable.html
+    /// [`Cancellable`]: trait.Cancellable.html
+    /// [`Cancellable::cancel`]: trait.Cancellable.html#tymethod.cancel
+    /// [`Scheduler`]: trait.Scheduler.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn unsubscribe_on<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> UnsubscribeOn<Self, Cancellable, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler + Send + 'static,
+    {
+        hooks::on_assembly("unsubscribe_on", UnsubscribeOn::new(self, scheduler))
     }
 }
@@ -0,0 +1,147 @@
+use crate::{core, flow, hooks, parallel};
+use async_trait::async_trait;
+
+/// A [`Flow`] split into a fixed number of rails, which may process their items in parallel.
+///
+/// A `ParallelFlow` is created from a [`Flow`] via [`Flow::parallel`], which distributes the
+/// items of the flow over the rails in round-robin order. Each rail is a separate stream of
+/// signals with its own [`Subscription`], to which a separate [`Subscriber`] subscribes. The
+/// rails are merged back into a single [`Flow`] via [`sequential`] or
+/// [`sequential_ordered`].
+///
+/// Without [`run_on`] all rails are processed on the task emitting the items of the upstream
+/// [`Flow`].
+///
+/// [`Flow`]: trait.Flow.html
+/// [`Flow::parallel`]: trait.Flow.html#method.parallel
+/// [`Subscription`]: trait.Subscription.html
+/// [`Subscriber`]: trait.Subscriber.html
+/// [`run_on`]: trait.ParallelFlow.html#method.run_on
+/// [`sequential`]: trait.ParallelFlow.html#method.sequential
+/// [`sequential_ordered`]: trait.ParallelFlow.html#method.sequential_ordered
+#[async_trait]
+pub trait ParallelFlow<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    /// Returns the number of rails of the current `ParallelFlow`.
+    fn rails(&self) -> usize;
+
+    /// Subscribes the given subscribers to the rails of the current `ParallelFlow`, one
+    /// subscriber per rail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of subscribers is not equal to the number of rails.
+    async fn subscribe<Subscriber>(self, subscribers: Vec<Subscriber>)
+    where
+        Subscriber: core::Subscriber<Subscription, Item, Error> + Send + 'static;
+
+    /// Returns a `ParallelFlow` whose rails only emit those items for which `predicate`
+    /// returns `true`. For each dropped item, another item is requested from the upstream.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn filter<Predicate>(
+        self,
+        predicate: Predicate,
+    ) -> parallel::Filter<Self, Subscription, Item, Error, Predicate>
+    where
+        Self: Sized,
+        Predicate: Fn(&Item) -> bool + Send + Sync + 'static,
+    {
+        hooks::on_assembly("filter", parallel::Filter::new(self, predicate))
+    }
+
+    /// Returns a `ParallelFlow` whose rails emit the results of applying `unary_op` to the
+    /// items of the rails of the current `ParallelFlow`.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn map<ItemOut, UnaryOp>(
+        self,
+        unary_op: UnaryOp,
+    ) -> parallel::Map<Self, Subscription, Item, Error, ItemOut, UnaryOp>
+    where
+        Self: Sized,
+        UnaryOp: Fn(Item) -> ItemOut + Send + Sync + 'static,
+    {
+        hooks::on_assembly("map", parallel::Map::new(self, unary_op))
+    }
+
+    /// Returns a `ParallelFlow` whose rails each emit a single item once the corresponding
+    /// rail of the current `ParallelFlow` completes. The item is the result of folding the
+    /// items of the rail into the value returned by `initial_fn` using `reducer`.
+    ///
+    /// All items of the rails are requested at once.
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn reduce<Acc, InitialFn, Reducer>(
+        self,
+        initial_fn: InitialFn,
+        reducer: Reducer,
+    ) -> parallel::Reduce<Self, Subscription, Item, Error, Acc, InitialFn, Reducer>
+    where
+        Self: Sized,
+        Acc: Send + 'static,
+        InitialFn: Fn() -> Acc + Send + Sync + 'static,
+        Reducer: Fn(Acc, Item) -> Acc + Send + Sync + 'static,
+    {
+        hooks::on_assembly("reduce", parallel::Reduce::new(self, initial_fn, reducer))
+    }
+
+    /// Returns a `ParallelFlow` whose rails emit their items on the given [`Scheduler`], so
+    /// that the rails are processed in parallel if the scheduler uses multiple threads. Each
+    /// rail prefetches [`flow::default_buffer_capacity`] items.
+    ///
+    /// [`Scheduler`]: trait.Scheduler.html
+    /// [`flow::default_buffer_capacity`]: ../flow/fn.default_buffer_capacity.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn run_on<Scheduler>(
+        self,
+        scheduler: Scheduler,
+    ) -> parallel::RunOn<Self, Subscription, Item, Error, Scheduler>
+    where
+        Self: Sized,
+        Scheduler: core::Scheduler,
+    {
+        hooks::on_assembly(
+            "run_on",
+            parallel::RunOn::new(self, scheduler, flow::default_buffer_capacity()),
+        )
+    }
+
+    /// Returns a [`Flow`] which merges the rails of the current `ParallelFlow`, emitting the
+    /// items of the rails as they become available. Each rail prefetches
+    /// [`flow::default_buffer_capacity`] items.
+    ///
+    /// [`Flow`]: trait.Flow.html
+    /// [`flow::default_buffer_capacity`]: ../flow/fn.default_buffer_capacity.html
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn sequential(self) -> parallel::Sequential<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly(
+            "sequential",
+            parallel::Sequential::new(self, flow::default_buffer_capacity(), false),
+        )
+    }
+
+    /// Like [`sequential`], but takes the items from the rails in round-robin order. This
+    /// restores the order of the items of the [`Flow`] the current `ParallelFlow` was created
+    /// from, provided that each rail emits exactly one item per item it receives, as is the
+    /// case for [`map`] and [`run_on`].
+    ///
+    /// [`sequential`]: trait.ParallelFlow.html#method.sequential
+    /// [`Flow`]: trait.Flow.html
+    /// [`map`]: trait.ParallelFlow.html#method.map
+    /// [`run_on`]: trait.ParallelFlow.html#method.run_on
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn sequential_ordered(self) -> parallel::Sequential<Self, Subscription, Item, Error>
+    where
+        Self: Sized,
+    {
+        hooks::on_assembly(
+            "sequential_ordered",
+            parallel::Sequential::new(self, flow::default_buffer_capacity(), true),
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use std::task::{Context, Poll};
+
+/// A poll-based variant of [`Observer`] which avoids allocating a future per signal.
+///
+/// Before each call of one of the `start_*` methods, [`poll_ready`] must have returned
+/// `Poll::Ready`. `poll_ready` only returns `Poll::Ready` once all previously started signals
+/// have been fully processed, so polling it once more after the last signal guarantees that
+/// the signal has been delivered.
+///
+/// Any `RawObserver` can be used as an [`Observer`] via [`RawAsObserver`] and any `Observer`
+/// as a `RawObserver` via [`ObserverAsRaw`].
+///
+/// [`Observer`]: trait.Observer.html
+/// [`poll_ready`]: trait.RawObserver.html#tymethod.poll_ready
+/// [`RawAsObserver`]: ../observer/struct.RawAsObserver.html
+/// [`ObserverAsRaw`]: ../observer/struct.ObserverAsRaw.html
+pub trait RawObserver<Cancellable, Item, Error> {
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()>;
+    fn start_subscribe(&mut self, cancellable: Cancellable);
+    fn start_next(&mut self, item: Item);
+    fn start_error(&mut self, error: Error);
+    fn start_completed(&mut self);
+}
@@ -0,0 +1,24 @@
+use crate::flow;
+use std::task::{Context, Poll};
+
+/// A poll-based variant of [`Subscriber`] which avoids allocating a future per signal.
+///
+/// Before each call of one of the `start_*` methods, [`poll_ready`] must have returned
+/// `Poll::Ready`. `poll_ready` only returns `Poll::Ready` once all previously started signals
+/// have been fully processed, so polling it once more after the last signal guarantees that
+/// the signal has been delivered.
+///
+/// Any `RawSubscriber` can be used as a [`Subscriber`] via [`RawAsSubscriber`] and any
+/// `Subscriber` as a `RawSubscriber` via [`SubscriberAsRaw`].
+///
+/// [`Subscriber`]: trait.Subscriber.html
+/// [`poll_ready`]: trait.RawSubscriber.html#tymethod.poll_ready
+/// [`RawAsSubscriber`]: ../subscriber/struct.RawAsSubscriber.html
+/// [`SubscriberAsRaw`]: ../subscriber/struct.SubscriberAsRaw.html
+pub trait RawSubscriber<Subscription, Item, Error> {
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()>;
+    fn start_subscribe(&mut self, subscription: Subscription);
+    fn start_next(&mut self, item: Item);
+    fn start_error(&mut self, error: flow::Error<Error>);
+    fn start_completed(&mut self);
+}
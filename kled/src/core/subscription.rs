@@ -6,6 +6,22 @@ pub trait Subscription {
     async fn cancel(&self);
     async fn is_cancelled(&self) -> bool;
     async fn request(&self, count: usize);
+
+    /// Cancels the subscription without awaiting if the implementation supports it, which
+    /// avoids boxing a future. Returns `false` if [`cancel`] must be awaited instead.
+    ///
+    /// [`cancel`]: trait.Subscription.html#tymethod.cancel
+    fn try_cancel_now(&self) -> bool {
+        false
+    }
+
+    /// Requests `count` items without awaiting if the implementation supports it, which
+    /// avoids boxing a future. Returns `false` if [`request`] must be awaited instead.
+    ///
+    /// [`request`]: trait.Subscription.html#tymethod.request
+    fn try_request_now(&self, _count: usize) -> bool {
+        false
+    }
 }
 
 #[async_trait]
@@ -1,3 +1,6 @@
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum BufferStrategy {
     Error,
@@ -5,8 +8,129 @@ pub enum BufferStrategy {
     DropOldest,
 }
 
-static BUFFER_CAPACITY: usize = 128;
+/// Determines how [`create`] handles items that are emitted while the subscriber has no
+/// outstanding demand.
+///
+/// [`create`]: fn.create.html
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BackpressureStrategy {
+    /// Buffers up to the given number of items and applies the [`BufferStrategy`] on overflow.
+    ///
+    /// [`BufferStrategy`]: enum.BufferStrategy.html
+    Buffer(BufferStrategy, usize),
+    /// Drops items without demand.
+    Drop,
+    /// Terminates with `MissingBackpressure` on the first item without demand.
+    Error,
+    /// Keeps only the most recent item without demand.
+    Latest,
+    /// Emits all items regardless of the demand.
+    Missing,
+}
+
+static DEFAULT_BUFFER_CAPACITY: AtomicUsize = AtomicUsize::new(128);
 
+/// Returns the capacity used by [`BufferConfig::new`] and thus by `on_backpressure_buffer`.
+///
+/// [`BufferConfig::new`]: struct.BufferConfig.html#method.new
 pub fn default_buffer_capacity() -> usize {
-    BUFFER_CAPACITY
+    DEFAULT_BUFFER_CAPACITY.load(Ordering::Relaxed)
+}
+
+/// Sets the capacity returned by [`default_buffer_capacity`]. Buffers which have already
+/// been configured are not affected.
+///
+/// [`default_buffer_capacity`]: fn.default_buffer_capacity.html
+pub fn set_default_buffer_capacity(capacity: usize) {
+    DEFAULT_BUFFER_CAPACITY.store(capacity, Ordering::Relaxed);
+}
+
+type WeightFn<Item> = Box<dyn FnMut(&Item) -> usize + Send>;
+type OverflowFn<Item> = Box<dyn FnMut(Item) + Send>;
+
+/// The configuration of `on_backpressure_buffer_with`.
+///
+/// The capacity of the buffer is measured in units of the weight function, which defaults
+/// to one per item. If an item does not fit into the buffer, the [`BufferStrategy`] is
+/// applied and each item dropped because of it is passed to the overflow callback.
+///
+/// [`BufferStrategy`]: enum.BufferStrategy.html
+pub struct BufferConfig<Item> {
+    strategy: BufferStrategy,
+    capacity: Option<usize>,
+    weight_fn: Option<WeightFn<Item>>,
+    overflow_fn: Option<OverflowFn<Item>>,
+}
+
+impl<Item> BufferConfig<Item> {
+    /// Creates a bounded configuration with the [`default_buffer_capacity`].
+    ///
+    /// [`default_buffer_capacity`]: fn.default_buffer_capacity.html
+    pub fn new(strategy: BufferStrategy) -> Self {
+        Self {
+            strategy,
+            capacity: Some(default_buffer_capacity()),
+            weight_fn: None,
+            overflow_fn: None,
+        }
+    }
+
+    /// Creates a configuration for a buffer that never overflows.
+    pub fn unbounded() -> Self {
+        Self {
+            strategy: BufferStrategy::Error,
+            capacity: None,
+            weight_fn: None,
+            overflow_fn: None,
+        }
+    }
+
+    /// Sets the capacity of the buffer. If called on an [`unbounded`] configuration, the
+    /// buffer becomes bounded and fails with [`BufferStrategy::Error`] on overflow.
+    ///
+    /// [`unbounded`]: #method.unbounded
+    /// [`BufferStrategy::Error`]: enum.BufferStrategy.html#variant.Error
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the function which determines the weight of each item, e.g. its size in bytes.
+    pub fn weight<F>(mut self, weight_fn: F) -> Self
+    where
+        F: FnMut(&Item) -> usize + Send + 'static,
+    {
+        self.weight_fn = Some(Box::new(weight_fn));
+        self
+    }
+
+    /// Sets the function which is called with each item dropped due to an overflow.
+    pub fn on_overflow<F>(mut self, overflow_fn: F) -> Self
+    where
+        F: FnMut(Item) + Send + 'static,
+    {
+        self.overflow_fn = Some(Box::new(overflow_fn));
+        self
+    }
+
+    pub(crate) fn strategy(&self) -> BufferStrategy {
+        self.strategy
+    }
+
+    pub(crate) fn limit(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub(crate) fn weigh(&mut self, item: &Item) -> usize {
+        match self.weight_fn {
+            Some(ref mut weight_fn) => weight_fn(item),
+            None => 1,
+        }
+    }
+
+    pub(crate) fn overflow(&mut self, item: Item) {
+        if let Some(ref mut overflow_fn) = self.overflow_fn {
+            overflow_fn(item);
+        }
+    }
 }
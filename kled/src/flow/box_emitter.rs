@@ -1,41 +1,452 @@
 use crate::core;
 use crate::flow;
-use crate::subscription::*;
-use std::marker::PhantomData;
+use async_std::channel::{bounded, Receiver, Sender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
 
+type BoxedSubscriber<Item, Error> = Box<
+    dyn core::Subscriber<BoxEmitterSubscription<Item, Error>, Item, Error> + Send + 'static,
+>;
+
+type CancelFn = Box<dyn FnOnce() + Send + 'static>;
+
+/// A type-erased emitter which passes the emitted signals to a [`Subscriber`] according to
+/// the given [`BackpressureStrategy`].
+///
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+/// [`BackpressureStrategy`]: enum.BackpressureStrategy.html
 pub struct BoxEmitter<Item, Error> {
-    subscriber: Box<dyn core::Subscriber<ArcSubscription, Item, Error> + Send + 'static>,
-    stub: ArcSubscriptionStub,
-    phantom: PhantomData<(Item, Error)>,
+    data: Arc<Data<Item, Error>>,
+}
+
+struct Data<Item, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Item, Error>>>,
+    backpressure_strategy: flow::BackpressureStrategy,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+    stopped: AtomicBool,
+    wip: AtomicUsize,
+    queue: Mutex<Queue<Item, Error>>,
+    demand: (Sender<()>, Receiver<()>),
+    cancel_fn: parking_lot::Mutex<Option<CancelFn>>,
+}
+
+struct Queue<Item, Error> {
+    items: VecDeque<Item>,
+    terminal: Option<Result<(), flow::Error<Error>>>,
+    is_done: bool,
+}
+
+impl<Item, Error> Queue<Item, Error> {
+    fn terminate(&mut self, terminal: Result<(), flow::Error<Error>>) {
+        self.terminal = Some(terminal);
+        self.is_done = true;
+    }
+
+    fn fail(&mut self) {
+        self.items.clear();
+        self.terminate(Err(flow::Error::MissingBackpressure));
+    }
+}
+
+impl<Item, Error> Data<Item, Error> {
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.stop();
+    }
+
+    // Signals the emitter to stop emitting, e.g. because the subscription was cancelled or
+    // the selected backpressure strategy terminated the flow.
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        let cancel_fn = self.cancel_fn.lock().take();
+        if let Some(cancel_fn) = cancel_fn {
+            cancel_fn();
+        }
+        let _ = self.demand.0.try_send(());
+    }
 }
 
 impl<Item, Error> BoxEmitter<Item, Error> {
-    pub async fn from<Subscriber>(mut subscriber: Subscriber) -> Self
+    /// Creates an emitter that passes all items to the subscriber regardless of its demand,
+    /// i.e. [`BackpressureStrategy::Missing`].
+    ///
+    /// [`BackpressureStrategy::Missing`]: enum.BackpressureStrategy.html#variant.Missing
+    pub async fn from<Subscriber>(subscriber: Subscriber) -> Self
     where
-        Subscriber: core::Subscriber<ArcSubscription, Item, Error> + Send + 'static,
+        Subscriber: core::Subscriber<BoxEmitterSubscription<Item, Error>, Item, Error>
+            + Send
+            + 'static,
     {
-        let stub = ArcSubscriptionStub::default();
-        subscriber.on_subscribe(stub.subscription()).await;
-        Self {
-            subscriber: Box::new(subscriber),
-            stub,
-            phantom: PhantomData,
-        }
+        Self::with_backpressure_strategy(subscriber, flow::BackpressureStrategy::Missing).await
+    }
+
+    pub async fn with_backpressure_strategy<Subscriber>(
+        mut subscriber: Subscriber,
+        backpressure_strategy: flow::BackpressureStrategy,
+    ) -> Self
+    where
+        Subscriber: core::Subscriber<BoxEmitterSubscription<Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let data = Arc::new(Data {
+            subscriber: Mutex::new(None),
+            backpressure_strategy,
+            requested: AtomicUsize::default(),
+            cancelled: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+            wip: AtomicUsize::default(),
+            queue: Mutex::new(Queue {
+                items: VecDeque::new(),
+                terminal: None,
+                is_done: false,
+            }),
+            demand: bounded(1),
+            cancel_fn: parking_lot::Mutex::new(None),
+        });
+        subscriber
+            .on_subscribe(BoxEmitterSubscription::new(data.clone()))
+            .await;
+        *data.subscriber.lock().await = Some(Box::new(subscriber));
+        Self { data }
     }
 
     pub async fn on_next(&mut self, item: Item) {
-        self.subscriber.on_next(item).await;
+        {
+            let mut queue = self.data.queue.lock().await;
+            if queue.is_done || self.is_cancelled() {
+                return;
+            }
+            let outstanding = self
+                .data
+                .requested
+                .load(Ordering::SeqCst)
+                .saturating_sub(queue.items.len());
+            use flow::BackpressureStrategy::*;
+            match self.data.backpressure_strategy {
+                Missing => queue.items.push_back(item),
+                Error => {
+                    if outstanding > 0 {
+                        queue.items.push_back(item)
+                    } else {
+                        queue.fail();
+                        drop(queue);
+                        self.data.stop();
+                    }
+                }
+                Drop => {
+                    if outstanding > 0 {
+                        queue.items.push_back(item)
+                    }
+                }
+                Latest => {
+                    if outstanding == 0 {
+                        queue.items.pop_back();
+                    }
+                    queue.items.push_back(item);
+                }
+                Buffer(buffer_strategy, capacity) => {
+                    let buffered = queue
+                        .items
+                        .len()
+                        .saturating_sub(self.data.requested.load(Ordering::SeqCst));
+                    if buffered < capacity {
+                        queue.items.push_back(item);
+                    } else {
+                        match buffer_strategy {
+                            flow::BufferStrategy::Error => {
+                                queue.fail();
+                                drop(queue);
+                                self.data.stop();
+                            }
+                            flow::BufferStrategy::DropOldest => {
+                                queue.items.pop_front();
+                                queue.items.push_back(item);
+                            }
+                            flow::BufferStrategy::DropLatest => {}
+                        }
+                    }
+                }
+            }
+        }
+        drain(&self.data).await;
     }
 
     pub async fn on_error(&mut self, error: Error) {
-        self.subscriber.on_error(flow::Error::Upstream(error)).await;
+        self.terminate(Err(flow::Error::Upstream(error))).await;
     }
 
     pub async fn on_completed(&mut self) {
-        self.subscriber.on_completed().await;
+        self.terminate(Ok(())).await;
     }
 
+    async fn terminate(&mut self, terminal: Result<(), flow::Error<Error>>) {
+        {
+            let mut queue = self.data.queue.lock().await;
+            if queue.is_done {
+                return;
+            }
+            queue.terminate(terminal);
+        }
+        drain(&self.data).await;
+    }
+
+    /// Returns whether the emitter should stop emitting, either because the subscriber
+    /// cancelled its subscription or because the backpressure strategy terminated the flow.
     pub fn is_cancelled(&self) -> bool {
-        self.stub.is_cancelled()
+        self.data.stopped.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of items the subscriber has requested, but not yet received.
+    pub fn requested(&self) -> usize {
+        self.data.requested.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the subscriber has requested at least one item that it has not yet
+    /// received or cancelled its subscription.
+    pub async fn await_demand(&self) {
+        while self.requested() == 0 && !self.is_cancelled() {
+            if self.data.demand.1.recv().await.is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Registers `cancel_fn` to be called once the subscriber cancels its subscription or the
+    /// backpressure strategy terminates the flow, e.g. `BackpressureStrategy::Error` on
+    /// overflow. If the emitter is already cancelled, `cancel_fn` is called immediately. Only
+    /// the last registered function will be called.
+    pub fn on_cancel<CancelFn>(&mut self, cancel_fn: CancelFn)
+    where
+        CancelFn: FnOnce() + Send + 'static,
+    {
+        *self.data.cancel_fn.lock() = Some(Box::new(cancel_fn));
+        if self.is_cancelled() {
+            let cancel_fn = self.data.cancel_fn.lock().take();
+            if let Some(cancel_fn) = cancel_fn {
+                cancel_fn();
+            }
+        }
+    }
+}
+
+// Only one call of drain may emit items at once. Calls that happen while another drain is in
+// progress (e.g. reentrant calls of request from on_next) only increase wip, which causes the
+// active drain to loop once more.
+async fn drain<Item, Error>(data: &Data<Item, Error>) {
+    if data.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = data.subscriber.lock().await;
+        while let Some(current) = subscriber.as_mut() {
+            let mut queue = data.queue.lock().await;
+            if data.cancelled.load(Ordering::SeqCst) {
+                queue.items.clear();
+                queue.is_done = true;
+                drop(queue);
+                *subscriber = None;
+            } else if queue.items.is_empty() {
+                let terminal = queue.terminal.take();
+                drop(queue);
+                match terminal {
+                    Some(Ok(())) => current.on_completed().await,
+                    Some(Err(error)) => current.on_error(error).await,
+                    None => break,
+                }
+                *subscriber = None;
+            } else if data.requested.load(Ordering::SeqCst) > 0
+                || data.backpressure_strategy == flow::BackpressureStrategy::Missing
+            {
+                let item = queue.items.pop_front().unwrap();
+                drop(queue);
+                if data.requested.load(Ordering::SeqCst) > 0 {
+                    data.requested.fetch_sub(1, Ordering::SeqCst);
+                }
+                current.on_next(item).await;
+            } else {
+                break;
+            }
+        }
+        drop(subscriber);
+        missed = data.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+pub struct BoxEmitterSubscription<Item, Error> {
+    data: Arc<Data<Item, Error>>,
+}
+
+impl<Item, Error> BoxEmitterSubscription<Item, Error> {
+    fn new(data: Arc<Data<Item, Error>>) -> Self {
+        Self { data }
+    }
+}
+
+#[async_trait]
+impl<Item, Error> core::Subscription for BoxEmitterSubscription<Item, Error>
+where
+    Item: Send,
+    Error: Send,
+{
+    async fn cancel(&self) {
+        self.data.cancel();
+        drain(&self.data).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn request(&self, count: usize) {
+        self.data.requested.fetch_add(count, Ordering::SeqCst);
+        let _ = self.data.demand.0.try_send(());
+        drain(&self.data).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn missing_strategy() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow.clone().subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn buffer_strategy() {
+        let test_subscriber = TestSubscriber::new(1);
+        flow::create(
+            |mut emitter: flow::BoxEmitter<_, ()>| async move {
+                for i in 0..4 {
+                    emitter.on_next(i).await;
+                }
+                emitter.on_completed().await;
+            },
+            flow::BackpressureStrategy::Buffer(flow::BufferStrategy::Error, 16),
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        test_subscriber.request_direct(3).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+    }
+
+    #[async_std::test]
+    async fn buffer_strategy_overflow() {
+        let test_subscriber = TestSubscriber::default();
+        flow::create(
+            |mut emitter: flow::BoxEmitter<_, ()>| async move {
+                for i in 0..4 {
+                    emitter.on_next(i).await;
+                }
+                assert!(emitter.is_cancelled());
+            },
+            flow::BackpressureStrategy::Buffer(flow::BufferStrategy::Error, 2),
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::MissingBackpressure));
+    }
+
+    #[async_std::test]
+    async fn drop_strategy() {
+        let test_subscriber = TestSubscriber::new(2);
+        flow::create(
+            |mut emitter: flow::BoxEmitter<_, ()>| async move {
+                for i in 0..4 {
+                    emitter.on_next(i).await;
+                }
+                emitter.on_completed().await;
+            },
+            flow::BackpressureStrategy::Drop,
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+    }
+
+    #[async_std::test]
+    async fn latest_strategy() {
+        let test_subscriber = TestSubscriber::new(1);
+        flow::create(
+            |mut emitter: flow::BoxEmitter<_, ()>| async move {
+                for i in 0..4 {
+                    emitter.on_next(i).await;
+                }
+                emitter.on_completed().await;
+            },
+            flow::BackpressureStrategy::Latest,
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 3]);
+    }
+
+    #[async_std::test]
+    async fn error_strategy() {
+        let test_subscriber = TestSubscriber::new(1);
+        flow::create(
+            |mut emitter: flow::BoxEmitter<_, ()>| async move {
+                emitter.on_next(0).await;
+                emitter.on_next(1).await;
+                assert!(emitter.is_cancelled());
+            },
+            flow::BackpressureStrategy::Error,
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::MissingBackpressure));
+    }
+
+    #[async_std::test]
+    async fn await_demand() {
+        let test_subscriber = TestSubscriber::default();
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        flow::create(
+            |mut emitter: flow::BoxEmitter<_, ()>| async move {
+                for i in 0..4 {
+                    emitter.await_demand().await;
+                    assert!(emitter.requested() > 0);
+                    emitter.on_next(i).await;
+                }
+                emitter.on_completed().await;
+            },
+            flow::BackpressureStrategy::Error,
+        )
+        .subscribe_on(scheduler.clone())
+        .subscribe(test_subscriber.clone()).await;
+        while !test_subscriber.is_subscribed().await {
+            async_std::task::yield_now().await;
+        }
+        for _ in 0..4 {
+            test_subscriber.request_direct(1).await;
+        }
+        scheduler.join();
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
     }
 }
@@ -0,0 +1,146 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_trait::async_trait;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+type BoxedSubscriber<Item, Error> =
+    Box<dyn core::Subscriber<BoxSubscription, Item, Error> + Send + 'static>;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+// The generic subscribe method of Flow is not object-safe, therefore the boxed flow is
+// subscribed via this trait which takes the subscriber as a trait object.
+trait DynFlow<Item, Error>: Send {
+    fn subscribe_dyn(self: Box<Self>, subscriber: BoxedSubscriber<Item, Error>) -> BoxFuture;
+}
+
+struct Erased<Flow, Subscription> {
+    flow: Flow,
+    phantom: PhantomData<Subscription>,
+}
+
+impl<Flow, Subscription, Item, Error> DynFlow<Item, Error> for Erased<Flow, Subscription>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn subscribe_dyn(self: Box<Self>, subscriber: BoxedSubscriber<Item, Error>) -> BoxFuture {
+        Box::pin(async move {
+            self.flow.subscribe(BoxFlowSubscriber { subscriber }).await
+        })
+    }
+}
+
+/// A type-erased [`Flow`], which hides both the type of the upstream flow and the type of
+/// its subscription. This allows to store flows in struct fields or to return them from
+/// trait methods.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub struct BoxFlow<Item, Error> {
+    inner: Box<dyn DynFlow<Item, Error>>,
+}
+
+impl<Item, Error> BoxFlow<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn new<Flow, Subscription>(flow: Flow) -> Self
+    where
+        Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        Self {
+            inner: Box::new(Erased {
+                flow,
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Item, Error> core::Flow<BoxSubscription, Item, Error> for BoxFlow<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<BoxSubscription, Item, Error> + Send + 'static,
+    {
+        self.inner.subscribe_dyn(Box::new(subscriber)).await
+    }
+}
+
+struct BoxFlowSubscriber<Item, Error> {
+    subscriber: BoxedSubscriber<Item, Error>,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for BoxFlowSubscriber<Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe(BoxSubscription::new(subscription))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use crate::Never;
+
+    fn boxed_range(count: usize) -> BoxFlow<usize, Never> {
+        flow::range(0, count).map(|i| i * 2).boxed()
+    }
+
+    #[async_std::test]
+    async fn basic() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        boxed_range(3).subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 2, 4]);
+    }
+
+    #[async_std::test]
+    async fn request_and_cancel() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        flow::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            emitter.on_next(*state);
+            *state += 1;
+        })
+        .boxed()
+        .subscribe(test_subscriber.clone()).await;
+        test_subscriber.request_direct(2).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+        test_subscriber.cancel().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Cancelled);
+    }
+}
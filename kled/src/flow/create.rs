@@ -1,36 +1,55 @@
 use crate::core;
 use crate::flow;
-use crate::subscription::*;
 use async_trait::async_trait;
+use std::future::Future;
 use std::marker::PhantomData;
 
 #[derive(new, Clone)]
 #[doc(hidden)]
-pub struct FlowCreate<F, Item, Error> {
-    emitter_consumer: F,
+pub struct FlowCreate<EmitterFn, Item, Error> {
+    emitter_consumer: EmitterFn,
+    backpressure_strategy: flow::BackpressureStrategy,
     phantom: PhantomData<(Item, Error)>,
 }
 
 #[async_trait]
-impl<F, Item, Error> core::Flow<ArcSubscription, Item, Error> for FlowCreate<F, Item, Error>
+impl<EmitterFn, Fut, Item, Error> core::Flow<flow::BoxEmitterSubscription<Item, Error>, Item, Error>
+    for FlowCreate<EmitterFn, Item, Error>
 where
-    F: FnOnce(flow::BoxEmitter<Item, Error>) + Send,
+    EmitterFn: FnOnce(flow::BoxEmitter<Item, Error>) -> Fut + Send,
+    Fut: Future + Send,
     Item: Send + 'static,
     Error: Send + 'static,
 {
     async fn subscribe<Subscriber>(self, subscriber: Subscriber)
     where
-        Subscriber: core::Subscriber<ArcSubscription, Item, Error> + Send + 'static,
+        Subscriber: core::Subscriber<flow::BoxEmitterSubscription<Item, Error>, Item, Error>
+            + Send
+            + 'static,
     {
-        (self.emitter_consumer)(flow::BoxEmitter::from(subscriber).await);
+        let emitter =
+            flow::BoxEmitter::with_backpressure_strategy(subscriber, self.backpressure_strategy)
+                .await;
+        (self.emitter_consumer)(emitter).await;
     }
 }
 
-pub fn create<F, Item, Error>(emitter_consumer: F) -> FlowCreate<F, Item, Error>
+/// Returns a [`Flow`] that passes a [`BoxEmitter`] to `emitter_consumer` for each subscriber.
+/// Items emitted without outstanding demand are handled according to `backpressure_strategy`.
+/// The emitter exposes the current demand via `requested` and `await_demand`, so that
+/// well-behaved producers can emit only as many items as have been requested.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`BoxEmitter`]: struct.BoxEmitter.html
+pub fn create<EmitterFn, Fut, Item, Error>(
+    emitter_consumer: EmitterFn,
+    backpressure_strategy: flow::BackpressureStrategy,
+) -> FlowCreate<EmitterFn, Item, Error>
 where
-    F: FnOnce(flow::BoxEmitter<Item, Error>) + Send,
+    EmitterFn: FnOnce(flow::BoxEmitter<Item, Error>) -> Fut + Send,
+    Fut: Future + Send,
     Item: Send + 'static,
     Error: Send + 'static,
 {
-    FlowCreate::new(emitter_consumer)
+    FlowCreate::new(emitter_consumer, backpressure_strategy)
 }
@@ -0,0 +1,68 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct FlowDefer<F> {
+    factory: F,
+}
+
+#[async_trait]
+impl<F, Flow, Subscription, Item, Error> core::Flow<Subscription, Item, Error>
+    for FlowDefer<F>
+where
+    F: FnOnce() -> Flow + Send,
+    Flow: core::Flow<Subscription, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<Subscription, Item, Error> + Send + 'static,
+    {
+        (self.factory)().subscribe(subscriber).await;
+    }
+}
+
+/// Returns a [`Flow`] that calls `factory` for each subscribing [`Subscriber`] and
+/// subscribes it to the `Flow` returned by `factory`.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+pub fn defer<F, Flow, Subscription, Item, Error>(factory: F) -> FlowDefer<F>
+where
+    F: FnOnce() -> Flow + Send,
+    Flow: core::Flow<Subscription, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    FlowDefer::new(factory)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn defer() {
+        let test_subscriber = TestSubscriber::default();
+        let called = Arc::new(AtomicBool::new(false));
+        let called2 = called.clone();
+        let deferred = flow::defer(move || {
+            called2.store(true, Ordering::Relaxed);
+            vec![0, 1, 2].into_flow()
+        });
+        assert!(!called.load(Ordering::Relaxed));
+        deferred.subscribe(test_subscriber.clone()).await;
+        assert!(called.load(Ordering::Relaxed));
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+}
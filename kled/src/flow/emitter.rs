@@ -47,11 +47,11 @@ mod tests {
 
     #[async_std::test]
     async fn basic() {
-        let test_subscriber = TestSubscriber::new(1);
+        let test_subscriber = TestSubscriber::new(3);
         let scheduler = scheduler::NewThreadScheduler::default();
         vec![0, 1, 2]
             .into_flow()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), 3, false)
             .subscribe(test_subscriber.clone()).await;
         scheduler.join();
         assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
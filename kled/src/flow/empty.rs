@@ -0,0 +1,54 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct FlowEmpty<Item, Error> {
+    phantom: PhantomData<(Item, Error)>,
+}
+
+#[async_trait]
+impl<Item, Error> core::Flow<ArcSubscription, Item, Error> for FlowEmpty<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<ArcSubscription, Item, Error> + Send + 'static,
+    {
+        let mut emitter = flow::Emitter::from(subscriber).await;
+        if !emitter.is_cancelled() {
+            emitter.on_completed().await;
+        }
+    }
+}
+
+/// Returns a [`Flow`] that emits no items and completes immediately.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn empty<Item, Error>() -> FlowEmpty<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    FlowEmpty::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn empty() {
+        let test_subscriber = TestSubscriber::default();
+        flow::empty::<i32, ()>()
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![]);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct FlowError<Item, Error> {
+    error: Error,
+    phantom: PhantomData<Item>,
+}
+
+#[async_trait]
+impl<Item, Error> core::Flow<ArcSubscription, Item, Error> for FlowError<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<ArcSubscription, Item, Error> + Send + 'static,
+    {
+        let mut emitter = flow::Emitter::from(subscriber).await;
+        if !emitter.is_cancelled() {
+            emitter.on_error(self.error).await;
+        }
+    }
+}
+
+/// Returns a [`Flow`] that emits no items and terminates immediately with `error`.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn error<Item, Error>(error: Error) -> FlowError<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    FlowError::new(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn error() {
+        let test_subscriber = TestSubscriber::default();
+        flow::error::<i32, _>(42)
+            .subscribe(test_subscriber.clone())
+            .await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, vec![]);
+        assert_eq!(
+            test_subscriber.error().await,
+            Some(flow::Error::Upstream(42))
+        );
+    }
+}
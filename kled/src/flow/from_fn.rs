@@ -0,0 +1,34 @@
+use crate::core::IntoFlow;
+use crate::flow::IntoIterFlow;
+use std::iter::{self, FromFn};
+
+/// Returns a [`Flow`] that emits the items returned by `f` until it returns `None`, at
+/// which point the `Flow` completes.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn from_fn<F, Item>(f: F) -> IntoIterFlow<FromFn<F>>
+where
+    F: FnMut() -> Option<Item> + Send + 'static,
+    Item: Send + 'static,
+{
+    iter::from_fn(f).into_flow()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn from_fn() {
+        let test_subscriber = TestSubscriber::default();
+        let mut count = 0;
+        flow::from_fn(move || {
+            count += 1;
+            if count <= 3 { Some(count) } else { None }
+        })
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The emitter passed to the generator function of [`generate`]. The generator may call
+/// `on_next` at most once per invocation and may terminate the `Flow` by calling either
+/// `on_error` or `on_completed`.
+///
+/// [`generate`]: fn.generate.html
+pub struct GenerateEmitter<Item, Error> {
+    item: Option<Item>,
+    terminal: Option<Result<(), Error>>,
+}
+
+impl<Item, Error> Default for GenerateEmitter<Item, Error> {
+    fn default() -> Self {
+        Self {
+            item: None,
+            terminal: None,
+        }
+    }
+}
+
+impl<Item, Error> GenerateEmitter<Item, Error> {
+    pub fn on_next(&mut self, item: Item) {
+        const MSG: &str = "GenerateEmitter::on_next: called more than once per invocation";
+        assert!(self.item.is_none(), "{}", MSG);
+        self.item = Some(item);
+    }
+
+    pub fn on_error(&mut self, error: Error) {
+        self.terminal = Some(Err(error));
+    }
+
+    pub fn on_completed(&mut self) {
+        self.terminal = Some(Ok(()));
+    }
+}
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct FlowGenerate<State, GeneratorFn, Item, Error> {
+    initial_state: State,
+    generator: GeneratorFn,
+    phantom: PhantomData<(Item, Error)>,
+}
+
+#[async_trait]
+impl<State, GeneratorFn, Item, Error>
+    core::Flow<GenerateSubscription<State, GeneratorFn, Item, Error>, Item, Error>
+    for FlowGenerate<State, GeneratorFn, Item, Error>
+where
+    State: Send + 'static,
+    GeneratorFn: FnMut(&mut State, &mut GenerateEmitter<Item, Error>) + Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<GenerateSubscription<State, GeneratorFn, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let data = Arc::new(Data {
+            requested: AtomicUsize::default(),
+            cancelled: AtomicBool::new(false),
+            wip: AtomicUsize::default(),
+            inner: Mutex::new(None),
+        });
+        let mut subscriber = subscriber;
+        // The subscriber may request items in on_subscribe, but these will only be emitted once
+        // the subscriber has been moved into data.
+        subscriber
+            .on_subscribe(GenerateSubscription::new(data.clone()))
+            .await;
+        *data.inner.lock().await = Some(Inner {
+            subscriber: Box::new(subscriber),
+            state: self.initial_state,
+            generator: self.generator,
+        });
+        drain(&data).await;
+    }
+}
+
+/// Returns a [`Flow`] that calls `generator` with a mutable reference to its state, which is
+/// initialized with `initial_state`, and a [`GenerateEmitter`] once for each item requested by
+/// the downstream until the generator terminates the `Flow` or it is cancelled.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`GenerateEmitter`]: struct.GenerateEmitter.html
+pub fn generate<State, GeneratorFn, Item, Error>(
+    initial_state: State,
+    generator: GeneratorFn,
+) -> FlowGenerate<State, GeneratorFn, Item, Error>
+where
+    State: Send + 'static,
+    GeneratorFn: FnMut(&mut State, &mut GenerateEmitter<Item, Error>) + Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    FlowGenerate::new(initial_state, generator)
+}
+
+type BoxedSubscriber<State, GeneratorFn, Item, Error> = Box<
+    dyn core::Subscriber<GenerateSubscription<State, GeneratorFn, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+struct Inner<State, GeneratorFn, Item, Error> {
+    subscriber: BoxedSubscriber<State, GeneratorFn, Item, Error>,
+    state: State,
+    generator: GeneratorFn,
+}
+
+struct Data<State, GeneratorFn, Item, Error> {
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+    wip: AtomicUsize,
+    inner: Mutex<Option<Inner<State, GeneratorFn, Item, Error>>>,
+}
+
+// Only one call of drain may generate items at once. Calls that happen while another drain is
+// in progress (e.g. reentrant calls of request from on_next) only increase wip, which causes
+// the active drain to loop once more.
+async fn drain<State, GeneratorFn, Item, Error>(data: &Data<State, GeneratorFn, Item, Error>)
+where
+    GeneratorFn: FnMut(&mut State, &mut GenerateEmitter<Item, Error>),
+{
+    if data.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut inner = data.inner.lock().await;
+        if data.cancelled.load(Ordering::SeqCst) {
+            *inner = None;
+        }
+        while let Some(current) = inner.as_mut() {
+            if data.requested.load(Ordering::SeqCst) == 0 {
+                break;
+            }
+            let mut emitter = GenerateEmitter::default();
+            (current.generator)(&mut current.state, &mut emitter);
+            if let Some(item) = emitter.item {
+                data.requested.fetch_sub(1, Ordering::SeqCst);
+                current.subscriber.on_next(item).await;
+            }
+            let terminated = match emitter.terminal {
+                Some(Ok(())) => {
+                    current.subscriber.on_completed().await;
+                    true
+                }
+                Some(Err(error)) => {
+                    current.subscriber.on_error(flow::Error::Upstream(error)).await;
+                    true
+                }
+                None => false,
+            };
+            if terminated || data.cancelled.load(Ordering::SeqCst) {
+                *inner = None;
+            }
+        }
+        drop(inner);
+        missed = data.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+pub struct GenerateSubscription<State, GeneratorFn, Item, Error> {
+    data: Arc<Data<State, GeneratorFn, Item, Error>>,
+}
+
+impl<State, GeneratorFn, Item, Error> GenerateSubscription<State, GeneratorFn, Item, Error> {
+    fn new(data: Arc<Data<State, GeneratorFn, Item, Error>>) -> Self {
+        Self { data }
+    }
+}
+
+#[async_trait]
+impl<State, GeneratorFn, Item, Error> core::Subscription
+    for GenerateSubscription<State, GeneratorFn, Item, Error>
+where
+    State: Send,
+    GeneratorFn: FnMut(&mut State, &mut GenerateEmitter<Item, Error>) + Send,
+    Item: Send,
+    Error: Send,
+{
+    async fn cancel(&self) {
+        self.data.cancelled.store(true, Ordering::SeqCst);
+        drain(&self.data).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn request(&self, count: usize) {
+        self.data.requested.fetch_add(count, Ordering::SeqCst);
+        drain(&self.data).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn generate() {
+        let test_subscriber = TestSubscriber::new(2);
+        flow::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            emitter.on_next(*state);
+            *state += 1;
+        })
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        test_subscriber.request_direct(3).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[async_std::test]
+    async fn generate_completed() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        flow::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            if *state < 3 {
+                emitter.on_next(*state);
+                *state += 1;
+            } else {
+                emitter.on_completed();
+            }
+        })
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::core::IntoFlow;
+use crate::flow::IntoIterFlow;
+use std::iter::{once, Once};
+
+/// Returns a [`Flow`] that emits `item` and completes.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn just<Item>(item: Item) -> IntoIterFlow<Once<Item>>
+where
+    Item: Send + 'static,
+{
+    once(item).into_flow()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn just() {
+        let test_subscriber = TestSubscriber::default();
+        flow::just(42)
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![42]);
+    }
+}
@@ -1,12 +1,23 @@
 reexport_all! {
     mod backpressure;
     mod box_emitter;
+    mod box_flow;
     mod create;
+    mod defer;
     mod emitter;
+    mod empty;
     mod error;
+    mod flow_error;
+    mod from_fn;
     mod from_iter;
+    mod generate;
+    mod just;
+    mod never;
+    mod range;
+    mod repeat;
     mod signal;
     mod test_flow;
+    mod using;
 }
 
 pub mod operators;
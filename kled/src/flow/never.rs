@@ -0,0 +1,36 @@
+use crate::core;
+use crate::flow;
+use crate::subscription::*;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct FlowNever<Item, Error> {
+    phantom: PhantomData<(Item, Error)>,
+}
+
+#[async_trait]
+impl<Item, Error> core::Flow<ArcSubscription, Item, Error> for FlowNever<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<ArcSubscription, Item, Error> + Send + 'static,
+    {
+        flow::Emitter::from(subscriber).await;
+    }
+}
+
+/// Returns a [`Flow`] that emits neither items nor a terminal signal.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn never<Item, Error>() -> FlowNever<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    FlowNever::new()
+}
@@ -0,0 +1,80 @@
+use crate::core;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+/// The [`Subscription`] passed downstream by operators which hold back or drop items, such as
+/// [`MapAsync`] and [`FilterAsync`]. It keeps track of the number of items requested from the
+/// upstream which have not been received yet, so that the operator can emit all of its
+/// pending items once no further items will arrive without another request.
+///
+/// [`Subscription`]: ../../core/trait.Subscription.html
+/// [`MapAsync`]: struct.MapAsync.html
+/// [`FilterAsync`]: struct.FilterAsync.html
+pub struct DemandSubscription<Upstream> {
+    upstream: Arc<Upstream>,
+    outstanding: Arc<AtomicUsize>,
+}
+
+impl<Upstream> DemandSubscription<Upstream> {
+    pub(crate) fn new(upstream: Upstream) -> Self {
+        Self {
+            upstream: Arc::new(upstream),
+            outstanding: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Records that an item has been received from the upstream and returns whether all
+    /// requested items have been received.
+    pub(crate) fn on_received(&self) -> bool {
+        let outstanding = self.outstanding.load(Ordering::SeqCst);
+        // A misbehaving upstream may emit more items than requested.
+        outstanding == 0 || self.outstanding.fetch_sub(1, Ordering::SeqCst) == 1
+    }
+}
+
+impl<Upstream> Clone for DemandSubscription<Upstream> {
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            outstanding: self.outstanding.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for DemandSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.outstanding.fetch_add(count, Ordering::SeqCst);
+        self.upstream.request(count).await;
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        self.upstream.try_cancel_now()
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        // The demand is recorded before requesting, as the upstream may emit the requested
+        // items right away.
+        self.outstanding.fetch_add(count, Ordering::SeqCst);
+        if self.upstream.try_request_now(count) {
+            true
+        } else {
+            self.outstanding.fetch_sub(count, Ordering::SeqCst);
+            false
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "DoFinallySubscription<Subscription, FinallyFn>")]
+pub struct DoFinally<FinallyFn>
+where
+    FinallyFn: FnOnce(),
+{
+    finally_fn: FinallyFn,
+}
+
+struct DoFinallySubscriber<Subscriber, FinallyFn> {
+    subscriber: Subscriber,
+    finally_fn: Arc<Mutex<Option<FinallyFn>>>,
+}
+
+impl<Subscriber, FinallyFn> DoFinallySubscriber<Subscriber, FinallyFn> {
+    fn new(subscriber: Subscriber, finally_fn: FinallyFn) -> Self {
+        Self {
+            subscriber,
+            finally_fn: Arc::new(Mutex::new(Some(finally_fn))),
+        }
+    }
+}
+
+async fn run_once<FinallyFn>(finally_fn: &Mutex<Option<FinallyFn>>)
+where
+    FinallyFn: FnOnce(),
+{
+    let finally_fn = finally_fn.lock().await.take();
+    if let Some(finally_fn) = finally_fn {
+        finally_fn();
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, FinallyFn> core::Subscriber<Subscription, Item, Error>
+    for DoFinallySubscriber<Subscriber, FinallyFn>
+where
+    Subscriber: core::Subscriber<DoFinallySubscription<Subscription, FinallyFn>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FinallyFn: FnOnce() + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = DoFinallySubscription::new(subscription, self.finally_fn.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+        run_once(&self.finally_fn).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+        run_once(&self.finally_fn).await;
+    }
+}
+
+#[derive(new)]
+pub struct DoFinallySubscription<Upstream, FinallyFn> {
+    upstream: Upstream,
+    finally_fn: Arc<Mutex<Option<FinallyFn>>>,
+}
+
+#[async_trait]
+impl<Upstream, FinallyFn> core::Subscription for DoFinallySubscription<Upstream, FinallyFn>
+where
+    Upstream: core::Subscription + Send + Sync,
+    FinallyFn: FnOnce() + Send,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        run_once(&self.finally_fn).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await;
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.upstream.try_request_now(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_finally_completed() {
+        let test_subscriber = TestSubscriber::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = count.clone();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .do_finally(move || {
+                count2.fetch_add(1, Ordering::Relaxed);
+            })
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[async_std::test]
+    async fn do_finally_cancelled() {
+        let mut test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = count.clone();
+        test_flow
+            .clone()
+            .do_finally(move || {
+                count2.fetch_add(1, Ordering::Relaxed);
+            })
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+        test_subscriber.cancel().await;
+
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        assert!(test_flow.is_cancelled().await);
+    }
+}
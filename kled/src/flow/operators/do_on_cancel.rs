@@ -0,0 +1,116 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "DoOnCancelSubscription<Subscription, CancelFn>")]
+pub struct DoOnCancel<CancelFn>
+where
+    CancelFn: FnMut(),
+{
+    cancel_fn: CancelFn,
+}
+
+struct DoOnCancelSubscriber<Subscriber, CancelFn> {
+    subscriber: Subscriber,
+    cancel_fn: Arc<Mutex<CancelFn>>,
+}
+
+impl<Subscriber, CancelFn> DoOnCancelSubscriber<Subscriber, CancelFn> {
+    fn new(subscriber: Subscriber, cancel_fn: CancelFn) -> Self {
+        Self {
+            subscriber,
+            cancel_fn: Arc::new(Mutex::new(cancel_fn)),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, CancelFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnCancelSubscriber<Subscriber, CancelFn>
+where
+    Subscriber: core::Subscriber<DoOnCancelSubscription<Subscription, CancelFn>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CancelFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = DoOnCancelSubscription::new(subscription, self.cancel_fn.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct DoOnCancelSubscription<Upstream, CancelFn> {
+    upstream: Upstream,
+    cancel_fn: Arc<Mutex<CancelFn>>,
+}
+
+#[async_trait]
+impl<Upstream, CancelFn> core::Subscription for DoOnCancelSubscription<Upstream, CancelFn>
+where
+    Upstream: core::Subscription + Send + Sync,
+    CancelFn: FnMut() + Send,
+{
+    async fn cancel(&self) {
+        (&mut *self.cancel_fn.lock().await)();
+        self.upstream.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await;
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.upstream.try_request_now(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_cancel() {
+        let mut test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancelled2 = cancelled.clone();
+        test_flow
+            .clone()
+            .do_on_cancel(move || cancelled2.store(true, Ordering::Relaxed))
+            .subscribe(test_subscriber.clone()).await;
+        assert!(!cancelled.load(Ordering::Relaxed));
+        test_subscriber.cancel().await;
+
+        assert!(cancelled.load(Ordering::Relaxed));
+        assert!(test_flow.is_cancelled().await);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnCompleted<CompletedFn>
+where
+    CompletedFn: FnMut(),
+{
+    completed_fn: CompletedFn,
+}
+
+#[derive(new)]
+struct DoOnCompletedSubscriber<Subscriber, CompletedFn> {
+    subscriber: Subscriber,
+    completed_fn: CompletedFn,
+}
+
+#[subscriber]
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, CompletedFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnCompletedSubscriber<Subscriber, CompletedFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CompletedFn: FnMut() + Send,
+{
+    async fn on_completed(&mut self) {
+        (self.completed_fn)();
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_completed() {
+        let test_subscriber = TestSubscriber::default();
+        let completed = Arc::new(AtomicBool::new(false));
+        let completed2 = completed.clone();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .do_on_completed(move || completed2.store(true, Ordering::Relaxed))
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert!(completed.load(Ordering::Relaxed));
+    }
+}
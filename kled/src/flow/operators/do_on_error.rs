@@ -0,0 +1,66 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnError<ErrorFn>
+where
+    ErrorFn: FnMut(&flow::Error<Error>),
+{
+    error_fn: ErrorFn,
+}
+
+#[derive(new)]
+struct DoOnErrorSubscriber<Subscriber, ErrorFn> {
+    subscriber: Subscriber,
+    error_fn: ErrorFn,
+}
+
+#[subscriber]
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, ErrorFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnErrorSubscriber<Subscriber, ErrorFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorFn: FnMut(&flow::Error<Error>) + Send,
+{
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        (self.error_fn)(&error);
+        self.subscriber.on_error(error).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_error() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_item_type(());
+        let error = Arc::new(AtomicUsize::new(0));
+        let error2 = error.clone();
+        test_flow
+            .clone()
+            .do_on_error(move |e| {
+                if let flow::Error::Upstream(e) = e {
+                    error2.store(*e, Ordering::Relaxed);
+                }
+            })
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_error(42).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::Upstream(42)));
+        assert_eq!(error.load(Ordering::Relaxed), 42);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnNext<NextFn>
+where
+    NextFn: FnMut(&Item),
+{
+    next_fn: NextFn,
+}
+
+#[derive(new)]
+struct DoOnNextSubscriber<Subscriber, NextFn> {
+    subscriber: Subscriber,
+    next_fn: NextFn,
+}
+
+#[subscriber]
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, NextFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnNextSubscriber<Subscriber, NextFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NextFn: FnMut(&Item) + Send,
+{
+    async fn on_next(&mut self, item: Item) {
+        (self.next_fn)(&item);
+        self.subscriber.on_next(item).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_next() {
+        let test_subscriber = TestSubscriber::default();
+        let sum = Arc::new(AtomicUsize::new(0));
+        let sum2 = sum.clone();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .do_on_next(move |item| {
+                sum2.fetch_add(*item, Ordering::Relaxed);
+            })
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(sum.load(Ordering::Relaxed), 6);
+    }
+}
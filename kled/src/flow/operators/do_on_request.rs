@@ -0,0 +1,113 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "DoOnRequestSubscription<Subscription, RequestFn>")]
+pub struct DoOnRequest<RequestFn>
+where
+    RequestFn: FnMut(usize),
+{
+    request_fn: RequestFn,
+}
+
+struct DoOnRequestSubscriber<Subscriber, RequestFn> {
+    subscriber: Subscriber,
+    request_fn: Arc<Mutex<RequestFn>>,
+}
+
+impl<Subscriber, RequestFn> DoOnRequestSubscriber<Subscriber, RequestFn> {
+    fn new(subscriber: Subscriber, request_fn: RequestFn) -> Self {
+        Self {
+            subscriber,
+            request_fn: Arc::new(Mutex::new(request_fn)),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, RequestFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnRequestSubscriber<Subscriber, RequestFn>
+where
+    Subscriber: core::Subscriber<DoOnRequestSubscription<Subscription, RequestFn>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    RequestFn: FnMut(usize) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = DoOnRequestSubscription::new(subscription, self.request_fn.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct DoOnRequestSubscription<Upstream, RequestFn> {
+    upstream: Upstream,
+    request_fn: Arc<Mutex<RequestFn>>,
+}
+
+#[async_trait]
+impl<Upstream, RequestFn> core::Subscription for DoOnRequestSubscription<Upstream, RequestFn>
+where
+    Upstream: core::Subscription + Send + Sync,
+    RequestFn: FnMut(usize) + Send,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        (&mut *self.request_fn.lock().await)(count);
+        self.upstream.request(count).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_request() {
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let requested = Arc::new(AtomicUsize::new(0));
+        let requested2 = requested.clone();
+        test_flow
+            .clone()
+            .do_on_request(move |count| {
+                requested2.fetch_add(count, Ordering::Relaxed);
+            })
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(requested.load(Ordering::Relaxed), 2);
+        test_subscriber.request_direct(3).await;
+
+        assert_eq!(requested.load(Ordering::Relaxed), 5);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct DoOnSubscribe<SubscribeFn>
+where
+    SubscribeFn: FnMut(&Subscription),
+{
+    subscribe_fn: SubscribeFn,
+}
+
+#[derive(new)]
+struct DoOnSubscribeSubscriber<Subscriber, SubscribeFn> {
+    subscriber: Subscriber,
+    subscribe_fn: SubscribeFn,
+}
+
+#[subscriber]
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, SubscribeFn> core::Subscriber<Subscription, Item, Error>
+    for DoOnSubscribeSubscriber<Subscriber, SubscribeFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SubscribeFn: FnMut(&Subscription) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        (self.subscribe_fn)(&subscription);
+        self.subscriber.on_subscribe(subscription).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_subscribe() {
+        let test_subscriber = TestSubscriber::default();
+        let subscribed = Arc::new(AtomicBool::new(false));
+        let subscribed2 = subscribed.clone();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .do_on_subscribe(move |_| subscribed2.store(true, Ordering::Relaxed))
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert!(subscribed.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::DemandSubscription;
+use async_trait::async_trait;
+use futures::future::{self, Join, Ready};
+use futures::prelude::*;
+use futures::stream::FuturesOrdered;
+
+#[operator(type = "flow", subscription = "DemandSubscription<Subscription>")]
+pub struct FilterAsync<Fut, Predicate>
+where
+    Fut: Future<Output = bool> + Send,
+    Predicate: FnMut(&Item) -> Fut + Send,
+{
+    predicate: Predicate,
+    concurrency: usize,
+}
+
+struct FilterAsyncSubscriber<Subscriber, Subscription, Item, Fut, Predicate>
+where
+    Fut: Future,
+{
+    subscriber: Subscriber,
+    predicate: Predicate,
+    concurrency: usize,
+    subscription: Option<DemandSubscription<Subscription>>,
+    // The most recent items together with the futures deciding whether they are emitted,
+    // which are awaited in order once `concurrency` of them are pending or all requested
+    // items have been received.
+    pending: FuturesOrdered<Join<Ready<Item>, Fut>>,
+}
+
+impl<Subscriber, Subscription, Item, Fut, Predicate>
+    FilterAsyncSubscriber<Subscriber, Subscription, Item, Fut, Predicate>
+where
+    Fut: Future,
+{
+    fn new(subscriber: Subscriber, predicate: Predicate, concurrency: usize) -> Self {
+        Self {
+            subscriber,
+            predicate,
+            concurrency: concurrency.max(1),
+            subscription: None,
+            pending: FuturesOrdered::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, Fut, Predicate>
+    core::Subscriber<Subscription, Item, Error>
+    for FilterAsyncSubscriber<Subscriber, Subscription, Item, Fut, Predicate>
+where
+    Subscriber: core::Subscriber<DemandSubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Fut: Future<Output = bool> + Send,
+    Predicate: FnMut(&Item) -> Fut + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = DemandSubscription::new(subscription);
+        self.subscription = Some(subscription.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let keep = (self.predicate)(&item);
+        self.pending.push_back(future::join(future::ready(item), keep));
+        // Once all requested items have been received, the downstream will not request
+        // further items before receiving the pending ones.
+        let exhausted = self
+            .subscription
+            .as_ref()
+            .map_or(true, DemandSubscription::on_received);
+        let limit = if exhausted { 1 } else { self.concurrency };
+        while self.pending.len() >= limit {
+            match self.pending.next().await {
+                Some((item, true)) => self.subscriber.on_next(item).await,
+                // The dropped item still counted towards the downstream's demand.
+                Some((_, false)) => request_replacement(&self.subscription).await,
+                None => {}
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.pending = FuturesOrdered::new();
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        while let Some((item, keep)) = self.pending.next().await {
+            if keep {
+                self.subscriber.on_next(item).await;
+            }
+        }
+        self.subscriber.on_completed().await;
+    }
+}
+
+async fn request_replacement<Subscription>(subscription: &Option<DemandSubscription<Subscription>>)
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+{
+    if let Some(subscription) = subscription {
+        if !core::Subscription::try_request_now(subscription, 1) {
+            core::Subscription::request(subscription, 1).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use futures::future;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn filter_async() {
+        let test_subscriber = TestSubscriber::default();
+        (0..10)
+            .into_flow()
+            .filter_async(|a| future::ready(a % 3 == 0), 1)
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 3, 6, 9]);
+    }
+
+    #[async_std::test]
+    async fn requests_replacements() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let test_subscriber = TestSubscriber::new(2);
+        test_flow
+            .clone()
+            .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+            .filter_async(|a| future::ready(a % 2 == 1), 2)
+            .subscribe(test_subscriber.clone()).await;
+
+        test_flow.emit(0).await;
+        test_flow.emit(1).await;
+        assert_eq!(test_subscriber.items().await, vec![1]);
+        assert_eq!(*requests.lock().unwrap(), vec![2, 1]);
+        test_flow.emit(2).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1]);
+    }
+}
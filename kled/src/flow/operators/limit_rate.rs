@@ -0,0 +1,284 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "flow", subscription = "LimitRateSubscription<Subscription, Item, Error>")]
+pub struct LimitRate {
+    high_tide: usize,
+    low_tide: usize,
+}
+
+type BoxedSubscriber<Subscription, Item, Error> = Box<
+    dyn core::Subscriber<LimitRateSubscription<Subscription, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+pub struct LimitRateSubscriber<Subscription, Item, Error> {
+    data: Arc<Data<Subscription, Item, Error>>,
+}
+
+struct Data<Subscription, Item, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, Item, Error>>>,
+    upstream: Mutex<Option<Arc<Subscription>>>,
+    high_tide: usize,
+    low_tide: usize,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+    wip: AtomicUsize,
+    queue: Mutex<Queue<Item, Error>>,
+}
+
+struct Queue<Item, Error> {
+    items: VecDeque<Item>,
+    terminal: Option<Result<(), flow::Error<Error>>>,
+    consumed: usize,
+}
+
+impl<Subscription, Item, Error> LimitRateSubscriber<Subscription, Item, Error> {
+    pub fn new<Subscriber>(subscriber: Subscriber, high_tide: usize, low_tide: usize) -> Self
+    where
+        Subscriber: core::Subscriber<LimitRateSubscription<Subscription, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        Self {
+            data: Arc::new(Data {
+                subscriber: Mutex::new(Some(Box::new(subscriber))),
+                upstream: Mutex::new(None),
+                high_tide,
+                low_tide,
+                requested: AtomicUsize::default(),
+                cancelled: AtomicBool::new(false),
+                wip: AtomicUsize::default(),
+                queue: Mutex::new(Queue {
+                    items: VecDeque::new(),
+                    terminal: None,
+                    consumed: 0,
+                }),
+            }),
+        }
+    }
+}
+
+// The upstream subscription is cloned out of the lock, as the upstream may emit items from
+// within request, which in turn may cause further requests.
+async fn request_upstream<Subscription, Item, Error>(
+    data: &Data<Subscription, Item, Error>,
+    count: usize,
+) where
+    Subscription: core::Subscription,
+{
+    let upstream = data.upstream.lock().await.clone();
+    if let Some(upstream) = upstream {
+        if !upstream.try_request_now(count) {
+            upstream.request(count).await;
+        }
+    }
+}
+
+// Only one call of drain may emit items at once. Calls that happen while another drain is in
+// progress only increase wip, which causes the active drain to loop once more. Each time
+// low_tide items have been emitted, the same amount is requested from the upstream.
+async fn drain<Subscription, Item, Error>(data: &Data<Subscription, Item, Error>)
+where
+    Subscription: core::Subscription,
+{
+    if data.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+        return;
+    }
+    let mut missed = 1;
+    loop {
+        let mut subscriber = data.subscriber.lock().await;
+        while let Some(current) = subscriber.as_mut() {
+            let mut queue = data.queue.lock().await;
+            if data.cancelled.load(Ordering::SeqCst) {
+                queue.items.clear();
+                drop(queue);
+                *subscriber = None;
+            } else if queue.items.is_empty() {
+                let terminal = queue.terminal.take();
+                drop(queue);
+                match terminal {
+                    Some(Ok(())) => current.on_completed().await,
+                    Some(Err(error)) => current.on_error(error).await,
+                    None => break,
+                }
+                *subscriber = None;
+            } else if data.requested.load(Ordering::SeqCst) > 0 {
+                let item = queue.items.pop_front().unwrap();
+                queue.consumed += 1;
+                let replenish = queue.consumed == data.low_tide;
+                if replenish {
+                    queue.consumed = 0;
+                }
+                drop(queue);
+                data.requested.fetch_sub(1, Ordering::SeqCst);
+                current.on_next(item).await;
+                if replenish {
+                    request_upstream(data, data.low_tide).await;
+                }
+            } else {
+                break;
+            }
+        }
+        drop(subscriber);
+        missed = data.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+        if missed == 0 {
+            break;
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for LimitRateSubscriber<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        *self.data.upstream.lock().await = Some(Arc::new(subscription));
+        // The subscriber is taken out of data during on_subscribe, so that requests from
+        // within on_subscribe do not block on the lock.
+        let subscriber = self.data.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber
+                .on_subscribe(LimitRateSubscription::new(self.data.clone()))
+                .await;
+            *self.data.subscriber.lock().await = Some(subscriber);
+        }
+        request_upstream(&self.data, self.data.high_tide).await;
+        drain(&self.data).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.data.queue.lock().await.items.push_back(item);
+        drain(&self.data).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.data.queue.lock().await.terminal = Some(Err(error));
+        drain(&self.data).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.queue.lock().await.terminal = Some(Ok(()));
+        drain(&self.data).await;
+    }
+}
+
+pub struct LimitRateSubscription<Subscription, Item, Error> {
+    data: Arc<Data<Subscription, Item, Error>>,
+}
+
+impl<Subscription, Item, Error> LimitRateSubscription<Subscription, Item, Error> {
+    fn new(data: Arc<Data<Subscription, Item, Error>>) -> Self {
+        Self { data }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscription
+    for LimitRateSubscription<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync,
+    Item: Send,
+    Error: Send,
+{
+    async fn cancel(&self) {
+        self.data.cancelled.store(true, Ordering::SeqCst);
+        let upstream = self.data.upstream.lock().await.clone();
+        if let Some(upstream) = upstream {
+            upstream.cancel().await;
+        }
+        drain(&self.data).await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn request(&self, count: usize) {
+        self.data.requested.fetch_add(count, Ordering::SeqCst);
+        drain(&self.data).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn limit_rate() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_subscriber = TestSubscriber::new(1);
+        flow::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            emitter.on_next(*state);
+            *state += 1;
+        })
+        .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+        .limit_rate(4, 3)
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        assert_eq!(*requests.lock().unwrap(), vec![4]);
+        test_subscriber.request_direct(2).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+        assert_eq!(*requests.lock().unwrap(), vec![4, 3]);
+    }
+
+    #[async_std::test]
+    async fn rebatch_requests() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_subscriber = TestSubscriber::new(10);
+        flow::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            if *state < 10 {
+                emitter.on_next(*state);
+                *state += 1;
+            } else {
+                emitter.on_completed();
+            }
+        })
+        .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+        .rebatch_requests(4)
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, (0..10).collect::<Vec<_>>());
+        assert_eq!(*requests.lock().unwrap(), vec![4, 3, 3, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rebatch_requests: count must be positive")]
+    fn rebatch_requests_zero() {
+        let _ = TestFlow::<i32, ()>::default().rebatch_requests(0);
+    }
+
+    #[async_std::test]
+    async fn error_after_items() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .limit_rate(4, 3)
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+}
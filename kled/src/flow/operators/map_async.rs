@@ -0,0 +1,178 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::DemandSubscription;
+use async_trait::async_trait;
+use futures::prelude::*;
+use futures::stream::FuturesOrdered;
+
+#[operator(
+    type = "flow",
+    subscription = "DemandSubscription<Subscription>",
+    item = "ItemOut"
+)]
+pub struct MapAsync<ItemOut, Fut, UnaryOp>
+where
+    Fut: Future<Output = ItemOut> + Send,
+    UnaryOp: FnMut(Item) -> Fut + Send,
+{
+    unary_op: UnaryOp,
+    concurrency: usize,
+}
+
+struct MapAsyncSubscriber<Subscriber, Subscription, Fut, UnaryOp>
+where
+    Fut: Future,
+{
+    subscriber: Subscriber,
+    unary_op: UnaryOp,
+    concurrency: usize,
+    subscription: Option<DemandSubscription<Subscription>>,
+    // The futures created for the most recent items, whose results are emitted in order once
+    // `concurrency` of them are pending or all requested items have been received.
+    pending: FuturesOrdered<Fut>,
+}
+
+impl<Subscriber, Subscription, Fut, UnaryOp> MapAsyncSubscriber<Subscriber, Subscription, Fut, UnaryOp>
+where
+    Fut: Future,
+{
+    fn new(subscriber: Subscriber, unary_op: UnaryOp, concurrency: usize) -> Self {
+        Self {
+            subscriber,
+            unary_op,
+            concurrency: concurrency.max(1),
+            subscription: None,
+            pending: FuturesOrdered::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, ItemIn, Subscriber, ItemOut, Error, Fut, UnaryOp>
+    core::Subscriber<Subscription, ItemIn, Error>
+    for MapAsyncSubscriber<Subscriber, Subscription, Fut, UnaryOp>
+where
+    Subscriber: core::Subscriber<DemandSubscription<Subscription>, ItemOut, Error> + Send,
+    Subscription: Send + Sync + 'static,
+    ItemIn: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+    Fut: Future<Output = ItemOut> + Send,
+    UnaryOp: FnMut(ItemIn) -> Fut + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = DemandSubscription::new(subscription);
+        self.subscription = Some(subscription.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: ItemIn) {
+        self.pending.push_back((self.unary_op)(item));
+        // Once all requested items have been received, the downstream will not request
+        // further items before receiving the pending ones.
+        let exhausted = self
+            .subscription
+            .as_ref()
+            .map_or(true, DemandSubscription::on_received);
+        let limit = if exhausted { 1 } else { self.concurrency };
+        while self.pending.len() >= limit {
+            if let Some(item) = self.pending.next().await {
+                self.subscriber.on_next(item).await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.pending = FuturesOrdered::new();
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        while let Some(item) = self.pending.next().await {
+            self.subscriber.on_next(item).await;
+        }
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn map_async() {
+        let test_subscriber = TestSubscriber::default();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .map_async(|a| async move { a + 1 }, 1)
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2, 3, 4]);
+    }
+
+    #[async_std::test]
+    async fn concurrency_respects_demand() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let test_subscriber = TestSubscriber::new(2);
+        test_flow
+            .clone()
+            .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+            .map_async(|a| async move { a * 2 }, 4)
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(*requests.lock().unwrap(), vec![2]);
+        test_flow.emit(0).await;
+        assert!(test_subscriber.items().await.is_empty());
+        test_flow.emit(1).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 2]);
+        test_subscriber.request_direct(1).await;
+        test_flow.emit(2).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 2, 4]);
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+    }
+
+    #[async_std::test]
+    async fn error_drops_pending() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let test_subscriber = TestSubscriber::new(10);
+        test_flow
+            .clone()
+            .map_async(|a| async move { a * 2 }, 2)
+            .subscribe(test_subscriber.clone()).await;
+
+        test_flow.emit_all(0..3).await;
+        test_flow.emit_error(()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, vec![0, 2]);
+    }
+
+    #[async_std::test]
+    async fn for_each_async() {
+        let sum = Arc::new(AtomicUsize::new(0));
+        let sum_clone = sum.clone();
+        (0..4)
+            .into_flow()
+            .for_each_async(
+                move |a| {
+                    let sum = sum_clone.clone();
+                    async move {
+                        sum.fetch_add(a, Ordering::SeqCst);
+                    }
+                },
+                2,
+            )
+            .await;
+
+        assert_eq!(sum.load(Ordering::SeqCst), 6);
+    }
+}
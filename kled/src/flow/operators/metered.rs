@@ -0,0 +1,130 @@
+use crate::{core, flow, metrics};
+use async_trait::async_trait;
+
+#[operator(type = "flow", subscription = "MeteredSubscription<Subscription>")]
+pub struct Metered {
+    label: &'static str,
+}
+
+#[derive(new)]
+struct MeteredSubscriber<Subscriber> {
+    subscriber: Subscriber,
+    label: &'static str,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> core::Subscriber<Subscription, Item, Error>
+    for MeteredSubscriber<Subscriber>
+where
+    Subscriber: core::Subscriber<MeteredSubscription<Subscription>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = MeteredSubscription::new(subscription, self.label);
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        metrics::increment_counter("items", self.label, 1);
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        metrics::increment_counter("errors", self.label, 1);
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        metrics::increment_counter("completions", self.label, 1);
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct MeteredSubscription<Upstream> {
+    upstream: Upstream,
+    label: &'static str,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for MeteredSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync,
+{
+    async fn cancel(&self) {
+        metrics::increment_counter("cancellations", self.label, 1);
+        self.upstream.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        metrics::increment_counter("requested", self.label, count as u64);
+        self.upstream.request(count).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::metrics;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn metered() {
+        let metrics = metrics::test_metrics();
+        let test_subscriber = TestSubscriber::new(4);
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .metered("flow_metered")
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(metrics.counter("items", "flow_metered"), 4);
+        assert_eq!(metrics.counter("requested", "flow_metered"), 4);
+        assert_eq!(metrics.counter("completions", "flow_metered"), 1);
+    }
+
+    #[async_std::test]
+    async fn counts_requests_and_cancellations() {
+        let metrics = metrics::test_metrics();
+        let mut test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        test_flow
+            .clone()
+            .metered("flow_metered_cancel")
+            .subscribe(test_subscriber.clone()).await;
+        test_subscriber.request_direct(3).await;
+        test_subscriber.cancel().await;
+
+        assert_eq!(metrics.counter("requested", "flow_metered_cancel"), 5);
+        assert_eq!(metrics.counter("cancellations", "flow_metered_cancel"), 1);
+    }
+
+    #[async_std::test]
+    async fn saturates_requested() {
+        let metrics = metrics::test_metrics();
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        test_flow
+            .clone()
+            .metered("flow_metered_unbounded")
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.request_direct(1).await;
+
+        assert_eq!(
+            metrics.counter("requested", "flow_metered_unbounded"),
+            u64::MAX
+        );
+    }
+}
@@ -1,12 +1,30 @@
 reexport_all! {
-    mod dematerialize;
-    mod map;
-    mod materialize;
+    mod demand_subscription;
+    mod do_finally;
+    mod do_on_cancel;
+    mod do_on_completed;
+    mod do_on_error;
+    mod do_on_next;
+    mod do_on_request;
+    mod do_on_subscribe;
+    mod filter_async;
+    mod limit_rate;
+    mod map_async;
+    mod metered;
     mod observe_on;
     mod on_backpressure_buffer;
     mod on_backpressure_drop;
     mod on_backpressure_error;
     mod on_backpressure_latest;
-    mod scan;
-    mod subscribe_on;
+    mod scan_async;
+    mod tap;
+    mod to_observable;
+    mod unsubscribe_on;
 }
+
+#[cfg(feature = "tracing")]
+mod traced;
+#[cfg(feature = "tracing")]
+pub use traced::*;
+
+pub use crate::operators::{Dematerialize, Map, Materialize, Scan, SubscribeOn};
@@ -1,33 +1,286 @@
-use crate::{core, Never};
-use crate::flow::operators::{Materialize, Dematerialize};
-use crate::flow::Signal;
-use crate::subscriber::ScheduledSubscriber;
+use crate::{core, flow};
+use async_std::channel::{bounded, Receiver, Sender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
 
 #[operator(
     type = "flow",
-    subscriber = "ScheduledSubscriber",
-    upstream_subscription = "Never",
-    upstream_item = "Signal<Subscription, Item, Error>",
-    upstream_error = "Never",
-    subscription = "Never",
-    item = "Signal<Subscription, Item, Error>",
-    error = "Never",
+    subscription = "ObserveOnSubscription<Subscription, Item, Error, Scheduler>"
 )]
-pub struct ObserveOnRaw<Scheduler>
+pub struct ObserveOn<Scheduler>
 where
     Scheduler: core::Scheduler
 {
     scheduler: Scheduler,
+    prefetch: usize,
+    delay_error: bool,
 }
 
-pub type ObserveOn<Upstream, Subscription, Item, Error, Scheduler> =
-    Dematerialize<
-        ObserveOnRaw<
-            Materialize<Upstream, Subscription, Item, Error>,
-            Subscription, Item, Error, Scheduler
-        >,
-        Subscription, Item, Error
-    >;
+type BoxedSubscriber<Subscription, Item, Error, Scheduler> = Box<
+    dyn core::Subscriber<ObserveOnSubscription<Subscription, Item, Error, Scheduler>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+pub struct ObserveOnSubscriber<Subscription, Item, Error, Scheduler> {
+    data: Arc<Data<Subscription, Item, Error, Scheduler>>,
+}
+
+struct Data<Subscription, Item, Error, Scheduler> {
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, Item, Error, Scheduler>>>,
+    upstream: Mutex<Option<Arc<Subscription>>>,
+    scheduler: Scheduler,
+    prefetch: usize,
+    delay_error: bool,
+    // The number of emitted items after which the same number is requested from upstream.
+    limit: usize,
+    consumed: AtomicUsize,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+    done: AtomicBool,
+    wip: AtomicUsize,
+    queue: (Sender<Item>, Receiver<Item>),
+    terminal: parking_lot::Mutex<Option<Result<(), flow::Error<Error>>>>,
+}
+
+impl<Subscription, Item, Error, Scheduler>
+    ObserveOnSubscriber<Subscription, Item, Error, Scheduler>
+{
+    pub fn new<Subscriber>(
+        subscriber: Subscriber,
+        scheduler: Scheduler,
+        prefetch: usize,
+        delay_error: bool,
+    ) -> Self
+    where
+        Subscriber: core::Subscriber<
+                ObserveOnSubscription<Subscription, Item, Error, Scheduler>,
+                Item,
+                Error,
+            > + Send
+            + 'static,
+    {
+        Self {
+            data: Arc::new(Data {
+                subscriber: Mutex::new(Some(Box::new(subscriber))),
+                upstream: Mutex::new(None),
+                scheduler,
+                prefetch,
+                delay_error,
+                limit: prefetch - prefetch / 4,
+                consumed: AtomicUsize::default(),
+                requested: AtomicUsize::default(),
+                cancelled: AtomicBool::new(false),
+                done: AtomicBool::new(false),
+                wip: AtomicUsize::default(),
+                queue: bounded(prefetch),
+                terminal: parking_lot::Mutex::new(None),
+            }),
+        }
+    }
+}
+
+impl<Subscription, Item, Error, Scheduler> Data<Subscription, Item, Error, Scheduler>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    // The upstream subscription is cloned out of the lock, as the upstream may emit items from
+    // within request.
+    async fn upstream(&self) -> Option<Arc<Subscription>> {
+        self.upstream.lock().await.clone()
+    }
+
+    fn clear(&self) {
+        while self.queue.1.try_recv().is_ok() {}
+    }
+
+    async fn drain(&self) {
+        let mut missed = 1;
+        loop {
+            let mut subscriber = self.subscriber.lock().await;
+            while let Some(current) = subscriber.as_mut() {
+                if self.cancelled.load(Ordering::SeqCst) {
+                    self.clear();
+                    *subscriber = None;
+                    break;
+                }
+                // done must be loaded before polling the queue, as otherwise items emitted
+                // right before the terminal signal could be missed.
+                let done = self.done.load(Ordering::SeqCst);
+                let failed = matches!(*self.terminal.lock(), Some(Err(_)));
+                if done && failed && !self.delay_error {
+                    self.clear();
+                }
+                if self.requested.load(Ordering::SeqCst) > 0 {
+                    if let Ok(item) = self.queue.1.try_recv() {
+                        self.requested.fetch_sub(1, Ordering::SeqCst);
+                        current.on_next(item).await;
+                        if self.consumed.fetch_add(1, Ordering::SeqCst) + 1 == self.limit {
+                            self.consumed.store(0, Ordering::SeqCst);
+                            if let Some(upstream) = self.upstream().await {
+                                if !upstream.try_request_now(self.limit) {
+                                    upstream.request(self.limit).await;
+                                }
+                            }
+                        }
+                        continue;
+                    }
+                }
+                if done && self.queue.1.is_empty() {
+                    let terminal = self.terminal.lock().take();
+                    match terminal {
+                        Some(Ok(())) => current.on_completed().await,
+                        Some(Err(error)) => current.on_error(error).await,
+                        None => {}
+                    }
+                    *subscriber = None;
+                }
+                break;
+            }
+            drop(subscriber);
+            missed = self.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+            if missed == 0 {
+                break;
+            }
+        }
+    }
+}
+
+// Schedules a drain on the scheduler unless a drain is already in progress, in which case only
+// wip is increased, which causes the active drain to loop once more.
+fn schedule<Subscription, Item, Error, Scheduler>(
+    data: &Arc<Data<Subscription, Item, Error, Scheduler>>,
+) where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    if data.wip.fetch_add(1, Ordering::SeqCst) == 0 {
+        let cloned = data.clone();
+        data.scheduler.schedule(async move { cloned.drain().await });
+    }
+}
+
+fn terminate<Subscription, Item, Error, Scheduler>(
+    data: &Arc<Data<Subscription, Item, Error, Scheduler>>,
+    terminal: Result<(), flow::Error<Error>>,
+) where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    *data.terminal.lock() = Some(terminal);
+    data.done.store(true, Ordering::SeqCst);
+    schedule(data);
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for ObserveOnSubscriber<Subscription, Item, Error, Scheduler>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
+        *self.data.upstream.lock().await = Some(subscription.clone());
+        // The subscriber is taken out of data during on_subscribe, so that a drain caused by
+        // a request from within on_subscribe does not emit items before on_subscribe returns.
+        let subscriber = self.data.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber
+                .on_subscribe(ObserveOnSubscription::new(self.data.clone()))
+                .await;
+            *self.data.subscriber.lock().await = Some(subscriber);
+        }
+        if !subscription.try_request_now(self.data.prefetch) {
+            subscription.request(self.data.prefetch).await;
+        }
+        schedule(&self.data);
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.data.done.load(Ordering::SeqCst) {
+            return;
+        }
+        if self.data.queue.0.try_send(item).is_err() {
+            // The upstream emitted more items than requested.
+            if let Some(upstream) = self.data.upstream().await {
+                upstream.cancel().await;
+            }
+            terminate(&self.data, Err(flow::Error::MissingBackpressure));
+        } else {
+            schedule(&self.data);
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if !self.data.done.load(Ordering::SeqCst) {
+            terminate(&self.data, Err(error));
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if !self.data.done.load(Ordering::SeqCst) {
+            terminate(&self.data, Ok(()));
+        }
+    }
+}
+
+pub struct ObserveOnSubscription<Subscription, Item, Error, Scheduler> {
+    data: Arc<Data<Subscription, Item, Error, Scheduler>>,
+}
+
+impl<Subscription, Item, Error, Scheduler>
+    ObserveOnSubscription<Subscription, Item, Error, Scheduler>
+{
+    fn new(data: Arc<Data<Subscription, Item, Error, Scheduler>>) -> Self {
+        Self { data }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Scheduler> core::Subscription
+    for ObserveOnSubscription<Subscription, Item, Error, Scheduler>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn cancel(&self) {
+        self.data.cancelled.store(true, Ordering::SeqCst);
+        if let Some(upstream) = self.data.upstream().await {
+            upstream.cancel().await;
+        }
+        schedule(&self.data);
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn request(&self, count: usize) {
+        core::Subscription::try_request_now(self, count);
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.data.requested.fetch_add(count, Ordering::SeqCst);
+        schedule(&self.data);
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -35,6 +288,8 @@ mod tests {
     use crate::prelude::*;
     use crate::scheduler;
     use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
 
     #[async_std::test]
     async fn observe_on() {
@@ -42,7 +297,7 @@ mod tests {
         let test_subscriber = TestSubscriber::new(4);
         vec![0, 1, 2, 3]
             .into_flow()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), 4, false)
             .subscribe(test_subscriber.clone()).await;
         scheduler.join();
         assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
@@ -55,7 +310,7 @@ mod tests {
         let test_subscriber = TestSubscriber::new(4);
         vec![0, 1, 2, 3]
             .into_flow()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), 4, false)
             .subscribe(test_subscriber.clone()).await;
         scheduler.join();
         assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
@@ -69,11 +324,100 @@ mod tests {
         let test_flow = TestFlow::default().annotate_item_type(());
         test_flow
             .clone()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), 4, false)
             .subscribe(test_subscriber.clone()).await;
         test_flow.emit_error(()).await;
         scheduler.join();
         assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
         assert_eq!(test_subscriber.error().await, Some(flow::Error::Upstream(())));
     }
+
+    #[async_std::test]
+    async fn observe_on_bounded() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_subscriber = TestSubscriber::default();
+        flow::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            emitter.on_next(*state);
+            *state += 1;
+        })
+        .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+        .observe_on(scheduler.clone(), 8, false)
+        .subscribe(test_subscriber.clone()).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![]);
+        assert_eq!(*requests.lock().unwrap(), vec![8]);
+        test_subscriber.request_direct(6).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(*requests.lock().unwrap(), vec![8, 6]);
+    }
+
+    #[async_std::test]
+    async fn observe_on_missing_backpressure() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .observe_on(scheduler.clone(), 2, false)
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::MissingBackpressure));
+        assert!(test_flow.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn observe_on_error_overtakes_items() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .observe_on(scheduler.clone(), 4, false)
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(vec![0, 1]).await;
+        test_flow.emit_error(()).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, vec![]);
+        test_subscriber.request_direct(2).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.items().await, vec![]);
+    }
+
+    #[async_std::test]
+    async fn observe_on_delay_error_thread_pool() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::new(100);
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .observe_on(scheduler.clone(), 128, true)
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(0..100).await;
+        test_flow.emit_error(()).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, (0..100).collect::<Vec<_>>());
+    }
+
+    #[async_std::test]
+    async fn observe_on_delay_error_new_thread() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_subscriber = TestSubscriber::new(100);
+        let test_flow = TestFlow::default();
+        test_flow
+            .clone()
+            .observe_on(scheduler.clone(), 128, true)
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(0..100).await;
+        test_flow.emit_error(()).await;
+        scheduler.join();
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.items().await, (0..100).collect::<Vec<_>>());
+    }
 }
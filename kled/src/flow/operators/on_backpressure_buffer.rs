@@ -1,8 +1,7 @@
-use crate::core;
-use crate::flow;
-use async_std::channel::{bounded, Receiver, Sender};
+use crate::{core, flow, metrics};
 use async_std::sync::Mutex;
 use async_trait::async_trait;
+use std::collections::VecDeque;
 #[chronobreak]
 use std::sync::atomic::{AtomicUsize, Ordering};
 #[chronobreak]
@@ -10,13 +9,12 @@ use std::sync::{Arc, Weak};
 
 #[operator(type = "flow", subscription = "OnBackpressureBufferSubscription<Subscription, Item, Error>")]
 pub struct OnBackpressureBuffer {
-    buffer_strategy: flow::BufferStrategy,
-    buffer_capacity: usize,
+    config: flow::BufferConfig<Item>,
 }
 
 pub struct OnBackpressureBufferSubscriber<Subscription, Item, Error> {
     data: Arc<Data<Subscription, Item, Error>>,
-    buffer_strategy: flow::BufferStrategy,
+    config: flow::BufferConfig<Item>,
 }
 
 type BoxedSubscriber<Subscription, Item, Error> = Box<
@@ -28,18 +26,50 @@ type BoxedSubscriber<Subscription, Item, Error> = Box<
 pub struct Data<Subscription, Item, Error> {
     subscriber: Mutex<Option<BoxedSubscriber<Subscription, Item, Error>>>,
     requested: AtomicUsize,
-    channel: (Sender<Item>, Receiver<Item>),
+    queue: Mutex<Queue<Item>>,
+}
+
+// Each item is stored with its weight, so that the weight function does not have to be
+// called again when the item is removed from the queue.
+struct Queue<Item> {
+    items: VecDeque<(Item, usize)>,
+    weight: usize,
+}
+
+impl<Item> Queue<Item> {
+    fn push(&mut self, item: Item, weight: usize) {
+        self.weight += weight;
+        self.items.push_back((item, weight));
+        metrics::adjust_gauge("queue_depth", "on_backpressure_buffer", 1);
+    }
+
+    fn pop(&mut self) -> Option<Item> {
+        self.items.pop_front().map(|(item, weight)| {
+            self.weight -= weight;
+            metrics::adjust_gauge("queue_depth", "on_backpressure_buffer", -1);
+            item
+        })
+    }
+
+    fn fits(&self, weight: usize, limit: usize) -> bool {
+        self.weight.saturating_add(weight) <= limit
+    }
+}
+
+impl<Item> Drop for Queue<Item> {
+    fn drop(&mut self) {
+        if !self.items.is_empty() {
+            let depth = self.items.len() as i64;
+            metrics::adjust_gauge("queue_depth", "on_backpressure_buffer", -depth);
+        }
+    }
 }
 
 impl<Subscription, Item, Error> OnBackpressureBufferSubscriber<Subscription, Item, Error>
 where
     Item: Send + 'static,
 {
-    pub fn new<Subscriber>(
-        subscriber: Subscriber,
-        buffer_strategy: flow::BufferStrategy,
-        buffer_capacity: usize,
-    ) -> Self
+    pub fn new<Subscriber>(subscriber: Subscriber, config: flow::BufferConfig<Item>) -> Self
     where
         Subscriber: core::Subscriber<
                 OnBackpressureBufferSubscription<Subscription, Item, Error>,
@@ -51,31 +81,47 @@ where
         let data = Arc::new(Data {
             subscriber: Mutex::new(Some(Box::new(subscriber))),
             requested: AtomicUsize::default(),
-            channel: bounded(buffer_capacity),
+            queue: Mutex::new(Queue {
+                items: VecDeque::new(),
+                weight: 0,
+            }),
         });
-        Self {
-            data,
-            buffer_strategy,
-        }
+        Self { data, config }
     }
 
-    async fn add_to_queue(&self, item: Item) {
-        if !self.data.channel.0.is_full() {
-            self.data.channel.0.send(item).await.unwrap();
-        } else {
-            use flow::BufferStrategy::*;
-            match self.buffer_strategy {
-                Error => {
-                    if let Some(mut subscriber) = self.data.subscriber.lock().await.take() {
-                        subscriber.on_error(flow::Error::MissingBackpressure).await
-                    };
+    async fn add_to_queue(&mut self, item: Item) {
+        let weight = self.config.weigh(&item);
+        let mut queue = self.data.queue.lock().await;
+        let limit = match self.config.limit() {
+            Some(limit) => limit,
+            None => return queue.push(item, weight),
+        };
+        if queue.fits(weight, limit) {
+            return queue.push(item, weight);
+        }
+        use flow::BufferStrategy::*;
+        match self.config.strategy() {
+            Error => {
+                drop(queue);
+                self.config.overflow(item);
+                if let Some(mut subscriber) = self.data.subscriber.lock().await.take() {
+                    subscriber.on_error(flow::Error::MissingBackpressure).await
+                };
+            }
+            DropOldest => {
+                while !queue.fits(weight, limit) {
+                    match queue.pop() {
+                        Some(oldest) => self.config.overflow(oldest),
+                        None => break,
+                    }
                 }
-                DropOldest => {
-                    self.data.channel.1.recv().await.unwrap();
-                    self.data.channel.0.send(item).await.unwrap();
+                if queue.fits(weight, limit) {
+                    queue.push(item, weight);
+                } else {
+                    self.config.overflow(item);
                 }
-                DropLatest => {}
             }
+            DropLatest => self.config.overflow(item),
         }
     }
 }
@@ -83,24 +129,17 @@ where
 async fn drain<Subscription, Item, Error>(
     data: &Arc<Data<Subscription, Item, Error>>,
     subscriber: &mut BoxedSubscriber<Subscription, Item, Error>,
-    mut requested: usize,
 ) {
-    let mut emitted = 0;
-    while emitted < requested {
-        let item = if let Ok(item) = data.channel.1.recv().await {
-            item
-        } else {
-            break;
+    // The count of requested items is reloaded in each iteration as on_next may have
+    // called request.
+    while data.requested.load(Ordering::Relaxed) > 0 {
+        let item = match data.queue.lock().await.pop() {
+            Some(item) => item,
+            None => break,
         };
+        data.requested.fetch_sub(1, Ordering::Relaxed);
         subscriber.on_next(item).await;
-        emitted += 1;
-        // If the loop would finish, update the count of requested items as
-        // on_next may have called request
-        if emitted == requested {
-            requested = data.requested.load(Ordering::Relaxed);
-        }
     }
-    data.requested.store(requested - emitted, Ordering::Relaxed);
 }
 
 #[async_trait]
@@ -124,7 +163,7 @@ where
         self.add_to_queue(item).await;
         if requested > 0 {
             if let Some(ref mut subscriber) = *self.data.subscriber.lock().await {
-                drain(&self.data, subscriber, requested).await;
+                drain(&self.data, subscriber).await;
             }
         }
     }
@@ -181,7 +220,7 @@ where
             // subscriber is borrowed mutably either here or in on_next
             if let Some(mut subscriber) = data.subscriber.try_lock() {
                 if let Some(mut subscriber) = (&mut *subscriber).as_mut() {
-                    drain(&data, &mut subscriber, requested).await
+                    drain(&data, &mut subscriber).await
                 };
             }
         }
@@ -193,6 +232,8 @@ mod tests {
     use crate::flow::*;
     use crate::prelude::*;
     use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
 
     #[async_std::test]
     async fn basic() {
@@ -276,4 +317,59 @@ mod tests {
         assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
         assert_eq!(test_subscriber.items().await, vec![0]);
     }
+
+    #[async_std::test]
+    async fn unbounded() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .on_backpressure_buffer_with(flow::BufferConfig::unbounded())
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(0..1000).await;
+        test_subscriber.request_direct(1000).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[async_std::test]
+    async fn on_overflow() {
+        let overflowed = Arc::new(Mutex::new(vec![]));
+        let overflowed_clone = overflowed.clone();
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .on_backpressure_buffer_with(
+                flow::BufferConfig::new(flow::BufferStrategy::DropOldest)
+                    .capacity(2)
+                    .on_overflow(move |item| overflowed_clone.lock().unwrap().push(item)),
+            )
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit_all(0..5).await;
+        test_subscriber.request_direct(2).await;
+        assert_eq!(test_subscriber.items().await, vec![3, 4]);
+        assert_eq!(*overflowed.lock().unwrap(), vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn weight() {
+        let test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .on_backpressure_buffer_with(
+                flow::BufferConfig::new(flow::BufferStrategy::DropLatest)
+                    .capacity(8)
+                    .weight(|item: &String| item.len()),
+            )
+            .subscribe(test_subscriber.clone()).await;
+        test_flow.emit("abc".to_owned()).await;
+        test_flow.emit("defg".to_owned()).await;
+        test_flow.emit("hi".to_owned()).await;
+        test_flow.emit("j".to_owned()).await;
+        test_subscriber.request_direct(3).await;
+        assert_eq!(test_subscriber.items().await, vec!["abc", "defg", "j"]);
+    }
 }
@@ -1,5 +1,4 @@
-use crate::core;
-use crate::flow;
+use crate::{core, flow, metrics};
 use async_trait::async_trait;
 use std::marker::PhantomData;
 #[chronobreak]
@@ -50,6 +49,8 @@ where
         if self.requested.load(Ordering::Relaxed) > 0 {
             self.subscriber.on_next(item).await;
             self.requested.fetch_sub(1, Ordering::Relaxed);
+        } else {
+            metrics::increment_counter("dropped", "on_backpressure_drop", 1);
         }
     }
 
@@ -84,6 +85,15 @@ where
     async fn request(&self, count: usize) {
         self.requested.fetch_add(count, Ordering::Relaxed);
     }
+
+    fn try_cancel_now(&self) -> bool {
+        self.upstream.try_cancel_now()
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.requested.fetch_add(count, Ordering::Relaxed);
+        true
+    }
 }
 
 #[cfg(test)]
@@ -103,6 +113,19 @@ mod tests {
         assert_eq!(test_subscriber.items().await, vec![0]);
     }
 
+    #[async_std::test]
+    async fn counts_dropped() {
+        let metrics = crate::metrics::test_metrics();
+        // Other tests may drop items concurrently.
+        let dropped = metrics.counter("dropped", "on_backpressure_drop");
+        let test_subscriber = TestSubscriber::new(1);
+        vec![0, 1, 2]
+            .into_flow()
+            .on_backpressure_drop()
+            .subscribe(test_subscriber.clone()).await;
+        assert!(metrics.counter("dropped", "on_backpressure_drop") >= dropped + 2);
+    }
+
     #[async_std::test]
     async fn drop_error() {
         let scheduler = scheduler::ThreadPoolScheduler::default();
@@ -111,7 +134,7 @@ mod tests {
         test_flow
             .clone()
             .on_backpressure_drop()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), 1, false)
             .subscribe(test_subscriber.clone()).await;
         test_flow.emit(0).await;
         test_flow.emit_error(()).await;
@@ -101,6 +101,15 @@ where
     async fn request(&self, count: usize) {
         self.requested.fetch_add(count, Ordering::Relaxed);
     }
+
+    fn try_cancel_now(&self) -> bool {
+        self.upstream.try_cancel_now()
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.requested.fetch_add(count, Ordering::Relaxed);
+        true
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,4 @@
-use crate::core;
-use crate::flow;
+use crate::{core, flow, metrics};
 use async_std::sync::Mutex;
 use async_trait::async_trait;
 use std::marker::PhantomData;
@@ -28,6 +27,16 @@ pub struct Data<Item> {
     latest: Mutex<Option<Item>>,
 }
 
+impl<Item> Data<Item> {
+    // Counts a replaced item as dropped, as it will never be emitted.
+    async fn replace_latest(&self, item: Option<Item>) {
+        let replaced = std::mem::replace(&mut *self.latest.lock().await, item);
+        if replaced.is_some() {
+            metrics::increment_counter("dropped", "on_backpressure_latest", 1);
+        }
+    }
+}
+
 impl<Subscription, Item, Error> OnBackpressureLatestSubscriber<Subscription, Item, Error>
 where
     Item: Send + 'static,
@@ -73,23 +82,23 @@ where
     async fn on_next(&mut self, item: Item) {
         if self.data.requested.load(Ordering::Relaxed) > 0 {
             let mut subscriber = self.subscriber.lock().await;
-            *self.data.latest.lock().await = None;
+            self.data.replace_latest(None).await;
             subscriber.on_next(item).await;
             self.data.requested.fetch_sub(1, Ordering::SeqCst);
         } else {
-            *self.data.latest.lock().await = Some(item);
+            self.data.replace_latest(Some(item)).await;
         }
     }
 
     async fn on_error(&mut self, error: flow::Error<Error>) {
         let mut subscriber = self.subscriber.lock().await;
-        *self.data.latest.lock().await = None;
+        self.data.replace_latest(None).await;
         subscriber.on_error(error).await;
     }
 
     async fn on_completed(&mut self) {
         let mut subscriber = self.subscriber.lock().await;
-        *self.data.latest.lock().await = None;
+        self.data.replace_latest(None).await;
         subscriber.on_completed().await;
     }
 }
@@ -0,0 +1,76 @@
+use crate::core;
+use async_trait::async_trait;
+use futures::prelude::*;
+use std::marker::PhantomData;
+
+#[operator(type = "flow", item = "ItemOut")]
+pub struct ScanAsync<ItemOut, Fut, BinaryOp>
+where
+    ItemOut: Clone,
+    Fut: Future<Output = ItemOut> + Send,
+    BinaryOp: FnMut(ItemOut, Item) -> Fut + Send,
+{
+    initial_value: ItemOut,
+    binary_op: BinaryOp,
+}
+
+struct ScanAsyncSubscriber<Subscriber, ItemOut, Fut, BinaryOp> {
+    subscriber: Subscriber,
+    previous_value: ItemOut,
+    binary_op: BinaryOp,
+    phantom: PhantomData<Fut>,
+}
+
+impl<Subscriber, ItemOut, Fut, BinaryOp> ScanAsyncSubscriber<Subscriber, ItemOut, Fut, BinaryOp> {
+    fn new(subscriber: Subscriber, initial_value: ItemOut, binary_op: BinaryOp) -> Self {
+        Self {
+            subscriber,
+            previous_value: initial_value,
+            binary_op,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[subscriber]
+#[async_trait]
+impl<Subscription, ItemIn, Subscriber, ItemOut, Error, Fut, BinaryOp>
+    core::Subscriber<Subscription, ItemIn, Error>
+    for ScanAsyncSubscriber<Subscriber, ItemOut, Fut, BinaryOp>
+where
+    Subscriber: core::Subscriber<Subscription, ItemOut, Error> + Send,
+    Subscription: Send + 'static,
+    ItemIn: Send + 'static,
+    ItemOut: Clone + Send + 'static,
+    Error: Send + 'static,
+    Fut: Future<Output = ItemOut> + Send,
+    BinaryOp: FnMut(ItemOut, ItemIn) -> Fut + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+        self.subscriber.on_next(self.previous_value.clone()).await;
+    }
+
+    async fn on_next(&mut self, item: ItemIn) {
+        self.previous_value = (self.binary_op)(self.previous_value.clone(), item).await;
+        self.subscriber.on_next(self.previous_value.clone()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn scan_async() {
+        let test_subscriber = TestSubscriber::default();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .scan_async(0, |a, b| async move { a + b })
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 0, 1, 3, 6]);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+
+#[operator(type = "flow")]
+pub struct Tap<NextFn, ErrorFn, CompletedFn>
+where
+    NextFn: FnMut(&Item),
+    ErrorFn: FnMut(&flow::Error<Error>),
+    CompletedFn: FnMut(),
+{
+    next_fn: NextFn,
+    error_fn: ErrorFn,
+    completed_fn: CompletedFn,
+}
+
+#[derive(new)]
+struct TapSubscriber<Subscriber, NextFn, ErrorFn, CompletedFn> {
+    subscriber: Subscriber,
+    next_fn: NextFn,
+    error_fn: ErrorFn,
+    completed_fn: CompletedFn,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, NextFn, ErrorFn, CompletedFn>
+    core::Subscriber<Subscription, Item, Error>
+    for TapSubscriber<Subscriber, NextFn, ErrorFn, CompletedFn>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NextFn: FnMut(&Item) + Send,
+    ErrorFn: FnMut(&flow::Error<Error>) + Send,
+    CompletedFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        (self.next_fn)(&item);
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        (self.error_fn)(&error);
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        (self.completed_fn)();
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn tap() {
+        let test_subscriber = TestSubscriber::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = count.clone();
+        let completed = Arc::new(AtomicBool::new(false));
+        let completed2 = completed.clone();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .tap(
+                move |_| {
+                    count2.fetch_add(1, Ordering::Relaxed);
+                },
+                |_| {},
+                move || completed2.store(true, Ordering::Relaxed),
+            )
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(count.load(Ordering::Relaxed), 4);
+        assert!(completed.load(Ordering::Relaxed));
+    }
+}
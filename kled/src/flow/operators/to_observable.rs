@@ -0,0 +1,169 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct ToObservable<Flow, Subscription, Item, Error> {
+    upstream: Flow,
+    phantom: PhantomData<(Subscription, Item, Error)>,
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error>
+    core::Observable<SubscriptionCancellable<Subscription>, Item, flow::Error<Error>>
+    for ToObservable<Flow, Subscription, Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<SubscriptionCancellable<Subscription>, Item, flow::Error<Error>>
+            + Send
+            + 'static,
+    {
+        self.upstream
+            .subscribe(ToObservableSubscriber::new(observer))
+            .await
+    }
+}
+
+#[derive(new)]
+struct ToObservableSubscriber<Observer> {
+    observer: Observer,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Observer> core::Subscriber<Subscription, Item, Error>
+    for ToObservableSubscriber<Observer>
+where
+    Observer: core::Observer<SubscriptionCancellable<Subscription>, Item, flow::Error<Error>>
+        + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
+        // The observer must be subscribed before requesting, as the upstream may emit items
+        // from within request.
+        self.observer
+            .on_subscribe(SubscriptionCancellable::new(subscription.clone()))
+            .await;
+        subscription.request(usize::MAX).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+/// The [`Cancellable`] of an [`Observable`] converted from a [`Flow`], which cancels the
+/// subscription of the `Flow`.
+///
+/// [`Cancellable`]: ../../core/trait.Cancellable.html
+/// [`Observable`]: ../../core/trait.Observable.html
+/// [`Flow`]: ../../core/trait.Flow.html
+#[derive(new)]
+pub struct SubscriptionCancellable<Subscription> {
+    subscription: Arc<Subscription>,
+}
+
+impl<Subscription> Clone for SubscriptionCancellable<Subscription> {
+    fn clone(&self) -> Self {
+        Self {
+            subscription: self.subscription.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription> core::Cancellable for SubscriptionCancellable<Subscription>
+where
+    Subscription: core::Subscription + Send + Sync,
+{
+    async fn cancel(&self) {
+        self.subscription.cancel().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn basic() {
+        let test_observer = TestObserver::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .to_observable()
+            .subscribe(test_observer.clone()).await;
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn requests_all_items() {
+        let requested = Arc::new(Mutex::new(vec![]));
+        let requested_clone = requested.clone();
+        let test_observer = TestObserver::default();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .do_on_request(move |count| requested_clone.lock().unwrap().push(count))
+            .to_observable()
+            .subscribe(test_observer.clone()).await;
+        assert_eq!(*requested.lock().unwrap(), vec![usize::MAX]);
+        test_flow.emit_all(vec![0, 1, 2]).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn upstream_error() {
+        let test_observer = TestObserver::default();
+        let test_flow = TestFlow::default().annotate_item_type(0);
+        test_flow
+            .clone()
+            .to_observable()
+            .subscribe(test_observer.clone()).await;
+        test_flow.emit_error(42).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.error().await, Some(flow::Error::Upstream(42)));
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_observer = TestObserver::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(0)
+            .annotate_error_type(());
+        test_flow
+            .clone()
+            .to_observable()
+            .subscribe(test_observer.clone()).await;
+        test_observer.cancel().await;
+        assert!(test_flow.is_cancelled().await);
+    }
+}
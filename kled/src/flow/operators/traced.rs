@@ -0,0 +1,194 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use tracing::{Instrument, Span};
+
+#[operator(type = "flow", subscription = "TracedSubscription<Subscription>")]
+pub struct Traced {
+    name: &'static str,
+}
+
+struct TracedSubscriber<Subscriber> {
+    subscriber: Subscriber,
+    name: &'static str,
+    span: Span,
+}
+
+impl<Subscriber> TracedSubscriber<Subscriber> {
+    fn new(subscriber: Subscriber, name: &'static str) -> Self {
+        Self {
+            subscriber,
+            name,
+            span: Span::none(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> core::Subscriber<Subscription, Item, Error>
+    for TracedSubscriber<Subscriber>
+where
+    Subscriber: core::Subscriber<TracedSubscription<Subscription>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.span = tracing::debug_span!("flow", name = self.name);
+        tracing::debug!(parent: &self.span, "on_subscribe");
+        let subscription = TracedSubscription::new(subscription, self.span.clone());
+        self.subscriber
+            .on_subscribe(subscription)
+            .instrument(self.span.clone())
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        tracing::trace!(parent: &self.span, "on_next");
+        self.subscriber
+            .on_next(item)
+            .instrument(self.span.clone())
+            .await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        tracing::debug!(parent: &self.span, "on_error");
+        self.subscriber
+            .on_error(error)
+            .instrument(self.span.clone())
+            .await;
+    }
+
+    async fn on_completed(&mut self) {
+        tracing::debug!(parent: &self.span, "on_completed");
+        self.subscriber
+            .on_completed()
+            .instrument(self.span.clone())
+            .await;
+    }
+}
+
+#[derive(new)]
+pub struct TracedSubscription<Upstream> {
+    upstream: Upstream,
+    span: Span,
+}
+
+#[async_trait]
+impl<Upstream> core::Subscription for TracedSubscription<Upstream>
+where
+    Upstream: core::Subscription + Send + Sync,
+{
+    async fn cancel(&self) {
+        tracing::debug!(parent: &self.span, "cancel");
+        self.upstream.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        tracing::trace!(parent: &self.span, count, "request");
+        self.upstream.request(count).await;
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        let cancelled = self.upstream.try_cancel_now();
+        if cancelled {
+            tracing::debug!(parent: &self.span, "cancel");
+        }
+        cancelled
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        let requested = self.upstream.try_request_now(count);
+        if requested {
+            tracing::trace!(parent: &self.span, count, "request");
+        }
+        requested
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TracedSubscription;
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    use crate::subscription::*;
+    use crate::tracing_recorder::*;
+
+    #[async_std::test]
+    async fn traced() {
+        let recorder = test_recorder();
+        let test_subscriber = TestSubscriber::new(4);
+        vec![0, 1]
+            .into_flow()
+            .traced("traced")
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        assert_eq!(
+            recorder.events("flow", "traced"),
+            vec![
+                RecordedEvent::new("on_subscribe", None),
+                RecordedEvent::new("request", Some(4)),
+                RecordedEvent::new("on_next", None),
+                RecordedEvent::new("request", Some(0)),
+                RecordedEvent::new("on_next", None),
+                RecordedEvent::new("request", Some(0)),
+                RecordedEvent::new("on_completed", None),
+            ]
+        );
+    }
+
+    #[async_std::test]
+    async fn forwards_request_and_cancel() {
+        let recorder = test_recorder();
+        let mut test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        test_flow
+            .clone()
+            .traced("forwards_request_and_cancel")
+            .subscribe(test_subscriber.clone())
+            .await;
+        test_subscriber.request_direct(3).await;
+        test_subscriber.cancel().await;
+
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(
+            recorder.events("flow", "forwards_request_and_cancel"),
+            vec![
+                RecordedEvent::new("on_subscribe", None),
+                RecordedEvent::new("request", Some(2)),
+                RecordedEvent::new("request", Some(3)),
+                RecordedEvent::new("cancel", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn forwards_try_request_now_and_try_cancel_now() {
+        let recorder = test_recorder();
+        let stub = ArcSubscriptionStub::default();
+        let span = tracing::debug_span!("flow", name = "forwards_try_now");
+        let subscription = TracedSubscription::new(stub.subscription(), span);
+        assert!(subscription.try_request_now(5));
+        assert!(subscription.try_cancel_now());
+
+        assert_eq!(stub.get_and_reset_requested(), 5);
+        assert!(stub.is_cancelled());
+        assert_eq!(
+            recorder.events("flow", "forwards_try_now"),
+            vec![
+                RecordedEvent::new("request", Some(5)),
+                RecordedEvent::new("cancel", None),
+            ]
+        );
+    }
+}
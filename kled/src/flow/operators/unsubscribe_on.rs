@@ -0,0 +1,160 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(
+    type = "flow",
+    subscription = "UnsubscribeOnSubscription<Subscription, Scheduler>"
+)]
+pub struct UnsubscribeOn<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+}
+
+#[derive(new)]
+struct UnsubscribeOnSubscriber<Subscriber, Scheduler> {
+    subscriber: Subscriber,
+    scheduler: Scheduler,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, Scheduler> core::Subscriber<Subscription, Item, Error>
+    for UnsubscribeOnSubscriber<Subscriber, Scheduler>
+where
+    Subscriber:
+        core::Subscriber<UnsubscribeOnSubscription<Subscription, Scheduler>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = UnsubscribeOnSubscription::new(subscription, self.scheduler.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+pub struct UnsubscribeOnSubscription<Upstream, Scheduler> {
+    upstream: Arc<Upstream>,
+    scheduler: Scheduler,
+    cancelled: AtomicBool,
+}
+
+impl<Upstream, Scheduler> UnsubscribeOnSubscription<Upstream, Scheduler> {
+    fn new(upstream: Upstream, scheduler: Scheduler) -> Self {
+        Self {
+            upstream: Arc::new(upstream),
+            scheduler,
+            cancelled: AtomicBool::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, Scheduler> core::Subscription for UnsubscribeOnSubscription<Upstream, Scheduler>
+where
+    Upstream: core::Subscription + Send + Sync + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn cancel(&self) {
+        core::Subscription::try_cancel_now(self);
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst) || self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await;
+    }
+
+    // Scheduling the cancellation never needs to await.
+    fn try_cancel_now(&self) -> bool {
+        // The upstream is cancelled at some later point on the scheduler, therefore the
+        // cancellation is recorded here so that is_cancelled reflects it immediately.
+        if !self.cancelled.swap(true, Ordering::SeqCst) {
+            let upstream = self.upstream.clone();
+            self.scheduler
+                .schedule(async move { upstream.cancel().await });
+        }
+        true
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.upstream.try_request_now(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn unsubscribe_on() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let cancel_thread = Arc::new(Mutex::new(None));
+        let cancel_thread_clone = cancel_thread.clone();
+        let mut test_subscriber = TestSubscriber::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        test_flow
+            .clone()
+            .do_on_cancel(move || {
+                *cancel_thread_clone.lock().unwrap() = Some(std::thread::current().id())
+            })
+            .unsubscribe_on(scheduler.clone())
+            .subscribe(test_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        scheduler.join();
+        assert!(test_flow.is_cancelled().await);
+        let cancel_thread = cancel_thread.lock().unwrap().unwrap();
+        assert_ne!(cancel_thread, std::thread::current().id());
+    }
+
+    #[async_std::test]
+    async fn request() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let requested = Arc::new(Mutex::new(vec![]));
+        let requested_clone = requested.clone();
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .do_on_request(move |count| requested_clone.lock().unwrap().push(count))
+            .unsubscribe_on(scheduler)
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(*requested.lock().unwrap(), vec![2]);
+        test_flow.emit_all(vec![0, 1]).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(*requested.lock().unwrap(), vec![2, 1]);
+        test_flow.emit(2).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::core::IntoFlow;
+use crate::flow::IntoIterFlow;
+use std::ops::Range;
+
+/// Returns a [`Flow`] that emits `count` sequential numbers starting at `start` and
+/// completes. The numbers stop before `usize::MAX` if `start + count` would overflow.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn range(start: usize, count: usize) -> IntoIterFlow<Range<usize>> {
+    (start..start.saturating_add(count)).into_flow()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn range() {
+        let test_subscriber = TestSubscriber::default();
+        flow::range(3, 4)
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![3, 4, 5, 6]);
+    }
+}
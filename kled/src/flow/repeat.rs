@@ -0,0 +1,33 @@
+use crate::flow::{self, FlowGenerate, GenerateEmitter};
+use crate::Never;
+
+type RepeatFn<Item> = fn(&mut Item, &mut GenerateEmitter<Item, Never>);
+
+/// Returns a [`Flow`] that emits a clone of `item` for each requested item until it is
+/// cancelled.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+pub fn repeat<Item>(item: Item) -> FlowGenerate<Item, RepeatFn<Item>, Item, Never>
+where
+    Item: Clone + Send + 'static,
+{
+    let generator: RepeatFn<Item> = |item, emitter| emitter.on_next(item.clone());
+    flow::generate(item, generator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn repeat() {
+        let test_subscriber = TestSubscriber::new(2);
+        flow::repeat(7)
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Subscribed);
+        assert_eq!(test_subscriber.items().await, vec![7, 7]);
+        test_subscriber.request_direct(1).await;
+        assert_eq!(test_subscriber.items().await, vec![7, 7, 7]);
+    }
+}
@@ -1,6 +1,5 @@
 use crate::core;
 use crate::flow;
-use crate::subscription::*;
 use async_std::sync::Mutex;
 use async_trait::async_trait;
 #[chronobreak]
@@ -83,14 +82,16 @@ impl<Item, Error> TestFlow<Item, Error> {
 }
 
 #[async_trait]
-impl<Item, Error> core::Flow<ArcSubscription, Item, Error> for TestFlow<Item, Error>
+impl<Item, Error> core::Flow<flow::BoxEmitterSubscription<Item, Error>, Item, Error> for TestFlow<Item, Error>
 where
     Item: Send + 'static,
     Error: Send + 'static,
 {
     async fn subscribe<Subscriber>(self, subscriber: Subscriber)
     where
-        Subscriber: core::Subscriber<ArcSubscription, Item, Error> + Send + 'static,
+        Subscriber: core::Subscriber<flow::BoxEmitterSubscription<Item, Error>, Item, Error>
+            + Send
+            + 'static,
     {
         assert!(!self.has_observer().await);
         let mut data = self.data.lock().await;
@@ -0,0 +1,279 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct FlowUsing<ResourceFactory, SourceFactory, DisposeFn> {
+    resource_factory: ResourceFactory,
+    source_factory: SourceFactory,
+    dispose_fn: DisposeFn,
+}
+
+#[async_trait]
+impl<ResourceFactory, SourceFactory, DisposeFn, Resource, Flow, Subscription, Item, Error>
+    core::Flow<UsingSubscription<Subscription, Resource, DisposeFn>, Item, Error>
+    for FlowUsing<ResourceFactory, SourceFactory, DisposeFn>
+where
+    ResourceFactory: FnOnce() -> Resource + Send,
+    SourceFactory: FnOnce(&Resource) -> Flow + Send,
+    DisposeFn: FnOnce(Resource) + Send + 'static,
+    Resource: Send + 'static,
+    Flow: core::Flow<Subscription, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber:
+            core::Subscriber<UsingSubscription<Subscription, Resource, DisposeFn>, Item, Error>
+                + Send
+                + 'static,
+    {
+        let resource = (self.resource_factory)();
+        let source = (self.source_factory)(&resource);
+        let disposer = Arc::new(Disposer::new(resource, self.dispose_fn));
+        source.subscribe(UsingSubscriber::new(subscriber, disposer)).await;
+    }
+}
+
+/// Returns a [`Flow`] that creates a resource with `resource_factory` for each subscribing
+/// [`Subscriber`] and subscribes it to the `Flow` returned by `source_factory`. The resource
+/// is passed to `dispose_fn` exactly once, as soon as the `Flow` completes, fails or is
+/// cancelled, whichever happens first.
+///
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+pub fn using<
+    ResourceFactory,
+    SourceFactory,
+    DisposeFn,
+    Resource,
+    Flow,
+    Subscription,
+    Item,
+    Error,
+>(
+    resource_factory: ResourceFactory,
+    source_factory: SourceFactory,
+    dispose_fn: DisposeFn,
+) -> FlowUsing<ResourceFactory, SourceFactory, DisposeFn>
+where
+    ResourceFactory: FnOnce() -> Resource + Send,
+    SourceFactory: FnOnce(&Resource) -> Flow + Send,
+    DisposeFn: FnOnce(Resource) + Send + 'static,
+    Resource: Send + 'static,
+    Flow: core::Flow<Subscription, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    FlowUsing::new(resource_factory, source_factory, dispose_fn)
+}
+
+// Holds the resource until it is disposed. The resource and dispose_fn are taken out under the
+// lock, which guarantees that dispose_fn is called at most once even if termination and
+// cancellation race.
+struct Disposer<Resource, DisposeFn> {
+    inner: parking_lot::Mutex<Option<(Resource, DisposeFn)>>,
+}
+
+impl<Resource, DisposeFn> Disposer<Resource, DisposeFn>
+where
+    DisposeFn: FnOnce(Resource),
+{
+    fn new(resource: Resource, dispose_fn: DisposeFn) -> Self {
+        Self {
+            inner: parking_lot::Mutex::new(Some((resource, dispose_fn))),
+        }
+    }
+
+    fn dispose(&self) {
+        let inner = self.inner.lock().take();
+        if let Some((resource, dispose_fn)) = inner {
+            dispose_fn(resource);
+        }
+    }
+}
+
+#[derive(new)]
+struct UsingSubscriber<Subscriber, Resource, DisposeFn> {
+    subscriber: Subscriber,
+    disposer: Arc<Disposer<Resource, DisposeFn>>,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, Resource, DisposeFn>
+    core::Subscriber<Subscription, Item, Error> for UsingSubscriber<Subscriber, Resource, DisposeFn>
+where
+    Subscriber: core::Subscriber<UsingSubscription<Subscription, Resource, DisposeFn>, Item, Error>
+        + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Resource: Send,
+    DisposeFn: FnOnce(Resource) + Send,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = UsingSubscription::new(subscription, self.disposer.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.disposer.dispose();
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.disposer.dispose();
+        self.subscriber.on_completed().await;
+    }
+}
+
+/// The [`Subscription`] of [`flow::using`], which disposes the resource after cancelling the
+/// source.
+///
+/// [`Subscription`]: ../core/trait.Subscription.html
+/// [`flow::using`]: fn.using.html
+pub struct UsingSubscription<Upstream, Resource, DisposeFn> {
+    upstream: Upstream,
+    disposer: Arc<Disposer<Resource, DisposeFn>>,
+}
+
+impl<Upstream, Resource, DisposeFn> UsingSubscription<Upstream, Resource, DisposeFn> {
+    fn new(upstream: Upstream, disposer: Arc<Disposer<Resource, DisposeFn>>) -> Self {
+        Self { upstream, disposer }
+    }
+}
+
+#[async_trait]
+impl<Upstream, Resource, DisposeFn> core::Subscription
+    for UsingSubscription<Upstream, Resource, DisposeFn>
+where
+    Upstream: core::Subscription + Send + Sync,
+    Resource: Send,
+    DisposeFn: FnOnce(Resource) + Send,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.disposer.dispose();
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        if !self.upstream.try_cancel_now() {
+            return false;
+        }
+        self.disposer.dispose();
+        true
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.upstream.request(count).await;
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.upstream.try_request_now(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn using_completed() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let disposed = Arc::new(AtomicUsize::new(0));
+        flow::using(
+            || disposed.clone(),
+            |_| vec![0, 1, 2].into_flow(),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn using_error() {
+        let test_subscriber = TestSubscriber::new(usize::MAX);
+        let disposed = Arc::new(AtomicUsize::new(0));
+        flow::using(
+            || disposed.clone(),
+            |_| flow::error::<(), _>(()),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn using_cancel() {
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let disposed = Arc::new(AtomicUsize::new(0));
+        flow::using(
+            || disposed.clone(),
+            |_| test_flow.clone(),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_subscriber.clone()).await;
+        assert_eq!(disposed.load(Ordering::SeqCst), 0);
+        test_subscriber.cancel().await;
+        test_subscriber.cancel().await;
+        assert!(test_flow.is_cancelled().await);
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn using_cancel_races_completion() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let mut test_subscriber = TestSubscriber::new(usize::MAX);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let disposed = Arc::new(AtomicUsize::new(0));
+        flow::using(
+            || disposed.clone(),
+            |_| test_flow.clone(),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_subscriber.clone()).await;
+        let test_flow_clone = test_flow.clone();
+        scheduler.schedule(async move {
+            test_flow_clone.emit_all(0..100).await;
+            test_flow_clone.emit_completed().await;
+        });
+        test_subscriber.cancel().await;
+        scheduler.join();
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,113 @@
+use std::panic::Location;
+#[chronobreak]
+use std::sync::Arc;
+
+type AssemblyHook = dyn Fn(&Assembly) + Send + Sync + 'static;
+
+static ON_ASSEMBLY: parking_lot::RwLock<Option<Arc<Box<AssemblyHook>>>> =
+    parking_lot::const_rwlock(None);
+
+/// Describes an operator which is being added to a pipeline, see [`set_on_assembly`].
+///
+/// [`set_on_assembly`]: fn.set_on_assembly.html
+#[derive(Copy, Clone, Debug)]
+pub struct Assembly {
+    operator: &'static str,
+    location: Option<&'static Location<'static>>,
+}
+
+impl Assembly {
+    #[cfg_attr(feature = "debug-assembly", track_caller)]
+    fn new(operator: &'static str) -> Self {
+        Self {
+            operator,
+            location: if cfg!(feature = "debug-assembly") {
+                Some(Location::caller())
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Returns the name of the operator method, e.g. `"map"`.
+    pub fn operator(&self) -> &'static str {
+        self.operator
+    }
+
+    /// Returns the location from which the operator method was called. The location is only
+    /// recorded if the `debug-assembly` feature is enabled.
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+}
+
+/// Sets the hook which is called each time an operator is constructed via one of the operator
+/// methods of [`Observable`], [`Flow`] or [`ParallelFlow`].
+///
+/// As operators are statically typed, the hook cannot replace the constructed operator, but
+/// it may e.g. count or log the assembled operators.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`ParallelFlow`]: ../core/trait.ParallelFlow.html
+pub fn set_on_assembly<Hook>(hook: Hook)
+where
+    Hook: Fn(&Assembly) + Send + Sync + 'static,
+{
+    let hook: Box<AssemblyHook> = Box::new(hook);
+    *ON_ASSEMBLY.write() = Some(Arc::new(hook));
+}
+
+/// Removes the hook set via [`set_on_assembly`].
+///
+/// [`set_on_assembly`]: fn.set_on_assembly.html
+pub fn reset_on_assembly() {
+    *ON_ASSEMBLY.write() = None;
+}
+
+/// Calls the hook set via [`set_on_assembly`] for the given operator and returns the operator.
+/// Operators defined outside of kled may call this to be visible to the hook as well.
+///
+/// If the `debug-assembly` feature is enabled, the operator is also recorded for the
+/// [`AssemblyTrace`] of the current thread.
+///
+/// [`set_on_assembly`]: fn.set_on_assembly.html
+/// [`AssemblyTrace`]: struct.AssemblyTrace.html
+#[cfg_attr(feature = "debug-assembly", track_caller)]
+pub fn on_assembly<Operator>(name: &'static str, operator: Operator) -> Operator {
+    let assembly = Assembly::new(name);
+    super::assembly_trace::record(assembly);
+    // The hook is cloned out of the lock, so that it may set or reset hooks itself.
+    let hook = ON_ASSEMBLY.read().clone();
+    if let Some(hook) = hook {
+        hook(&assembly);
+    }
+    operator
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hooks;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn on_assembly() {
+        let operators = Arc::new(Mutex::new(vec![]));
+        let operators_clone = operators.clone();
+        hooks::set_on_assembly(move |assembly| {
+            operators_clone.lock().unwrap().push(assembly.operator())
+        });
+        let _ = vec![0, 1, 2]
+            .into_flow()
+            .map(|a| a + 1)
+            .on_backpressure_drop();
+        hooks::reset_on_assembly();
+
+        // Other tests may assemble operators concurrently.
+        let operators = operators.lock().unwrap().clone();
+        assert!(operators.contains(&"map"));
+        assert!(operators.contains(&"on_backpressure_drop"));
+    }
+}
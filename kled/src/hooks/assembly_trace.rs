@@ -0,0 +1,216 @@
+use crate::hooks::Assembly;
+use std::fmt;
+#[chronobreak]
+use std::sync::Arc;
+
+#[cfg(feature = "debug-assembly")]
+mod recording {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::panic::{self, AssertUnwindSafe};
+
+    // Bounds the recorded operators of pipelines which are never subscribed to.
+    const CAPACITY: usize = 64;
+
+    thread_local! {
+        static ASSEMBLED: RefCell<VecDeque<Assembly>> = RefCell::new(VecDeque::new());
+        static CURRENT: RefCell<Option<AssemblyTrace>> = RefCell::new(None);
+    }
+
+    pub fn record(assembly: Assembly) {
+        ASSEMBLED.with(|assembled| {
+            let mut assembled = assembled.borrow_mut();
+            if assembled.len() == CAPACITY {
+                assembled.pop_front();
+            }
+            assembled.push_back(assembly);
+        });
+    }
+
+    pub fn take() -> AssemblyTrace {
+        let assemblies: Vec<Assembly> =
+            ASSEMBLED.with(|assembled| assembled.borrow_mut().drain(..).collect());
+        AssemblyTrace {
+            assemblies: Arc::new(assemblies),
+        }
+    }
+
+    pub fn current() -> AssemblyTrace {
+        CURRENT.with(|current| current.borrow().clone().unwrap_or_default())
+    }
+
+    pub fn scope<Fn, R>(trace: &AssemblyTrace, f: Fn) -> R
+    where
+        Fn: FnOnce() -> R,
+    {
+        let previous = CURRENT.with(|current| current.replace(Some(trace.clone())));
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+        match result {
+            Ok(result) => result,
+            Err(payload) => {
+                let message = match payload.downcast_ref::<&str>() {
+                    Some(message) => Some(message.to_string()),
+                    None => payload.downcast_ref::<String>().cloned(),
+                };
+                match message {
+                    Some(message) => panic!("{}\n{}", message, trace),
+                    // Other payloads may be downcast by the caller and are left untouched.
+                    None => panic::resume_unwind(payload),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "debug-assembly"))]
+mod recording {
+    use super::*;
+
+    pub fn record(_: Assembly) {}
+
+    pub fn take() -> AssemblyTrace {
+        AssemblyTrace::default()
+    }
+
+    pub fn current() -> AssemblyTrace {
+        AssemblyTrace::default()
+    }
+
+    pub fn scope<Fn, R>(_: &AssemblyTrace, f: Fn) -> R
+    where
+        Fn: FnOnce() -> R,
+    {
+        f()
+    }
+}
+
+pub(crate) use recording::record;
+
+/// The operators from which a pipeline was assembled, as recorded if the `debug-assembly`
+/// feature is enabled. Without the feature, all traces are empty.
+///
+/// The operators are recorded per thread by [`on_assembly`] and taken by
+/// [`LambdaObserver`] and [`LambdaSubscriber`] when they are created, i.e. when subscribing
+/// via `subscribe_next` or `subscribe_all`. Panics in the functions passed to these
+/// subscribers are extended by the trace and errors passed to
+/// [`on_undeliverable_error`] carry it in their [`UndeliverableError`].
+///
+/// As the operators are recorded per thread, the trace also contains operators of pipelines
+/// assembled on the same thread but never subscribed to.
+///
+/// [`on_assembly`]: fn.on_assembly.html
+/// [`LambdaObserver`]: ../observer/struct.LambdaObserver.html
+/// [`LambdaSubscriber`]: ../subscriber/struct.LambdaSubscriber.html
+/// [`on_undeliverable_error`]: fn.on_undeliverable_error.html
+/// [`UndeliverableError`]: struct.UndeliverableError.html
+pub struct AssemblyTrace {
+    assemblies: Arc<Vec<Assembly>>,
+}
+
+impl AssemblyTrace {
+    /// Takes the operators which were assembled on the current thread since the last call.
+    pub fn take() -> Self {
+        recording::take()
+    }
+
+    /// Returns the trace of the [`LambdaObserver`] or [`LambdaSubscriber`] currently calling
+    /// one of its functions on the current thread.
+    ///
+    /// [`LambdaObserver`]: ../observer/struct.LambdaObserver.html
+    /// [`LambdaSubscriber`]: ../subscriber/struct.LambdaSubscriber.html
+    pub fn current() -> Self {
+        recording::current()
+    }
+
+    /// Returns the recorded operators in the order in which they were assembled.
+    pub fn assemblies(&self) -> &[Assembly] {
+        &self.assemblies
+    }
+
+    /// Calls `f` with the current trace set to this trace. If `f` panics with a message, the
+    /// trace is appended to it, while panics with any other payload are resumed unchanged.
+    pub(crate) fn scope<Fn, R>(&self, f: Fn) -> R
+    where
+        Fn: FnOnce() -> R,
+    {
+        recording::scope(self, f)
+    }
+}
+
+impl Default for AssemblyTrace {
+    fn default() -> Self {
+        Self {
+            assemblies: Arc::new(vec![]),
+        }
+    }
+}
+
+impl Clone for AssemblyTrace {
+    fn clone(&self) -> Self {
+        Self {
+            assemblies: self.assemblies.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for AssemblyTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.assemblies.iter()).finish()
+    }
+}
+
+impl fmt::Display for AssemblyTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "assembled at:")?;
+        if self.assemblies.is_empty() {
+            return write!(f, " <unknown>");
+        }
+        for assembly in self.assemblies.iter().rev() {
+            write!(f, "\n    {}", assembly.operator())?;
+            if let Some(location) = assembly.location() {
+                write!(f, " at {}", location)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "debug-assembly"))]
+mod tests {
+    use crate::hooks::AssemblyTrace;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn records_locations() {
+        let _ = AssemblyTrace::take();
+        let line = line!() + 1;
+        let _ = vec![0, 1, 2].into_flow().map(|a| a + 1);
+        let trace = AssemblyTrace::take();
+
+        assert_eq!(trace.assemblies().len(), 1);
+        let location = trace.assemblies()[0].location().unwrap();
+        assert_eq!(location.file(), file!());
+        assert_eq!(location.line(), line);
+    }
+
+    #[async_std::test]
+    #[should_panic(expected = "assembled at:\n    map at")]
+    async fn extends_panics() {
+        vec![0, 1, 2]
+            .into_observable()
+            .map(|a| a + 1)
+            .subscribe_next(|_| panic!("next"))
+            .await;
+    }
+
+    #[test]
+    fn keeps_other_payloads() {
+        let trace = AssemblyTrace::default();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            trace.scope(|| std::panic::resume_unwind(Box::new(42)))
+        }));
+        assert_eq!(result.unwrap_err().downcast_ref::<i32>(), Some(&42));
+    }
+}
@@ -0,0 +1,13 @@
+//! Global hooks which allow plugging into kled without wrapping each pipeline, e.g. to add
+//! logging, tracing or metrics.
+//!
+//! Hooks are set process-wide and apply to all pipelines. Setting a hook replaces the
+//! previously set one.
+
+reexport_all! {
+    mod assembly;
+    mod assembly_trace;
+    mod protocol_violation;
+    mod schedule;
+    mod undeliverable_error;
+}
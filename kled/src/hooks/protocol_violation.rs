@@ -0,0 +1,235 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+type ProtocolViolationHook = dyn Fn(&ProtocolViolation) + Send + Sync + 'static;
+
+static ON_PROTOCOL_VIOLATION: parking_lot::RwLock<Option<Arc<Box<ProtocolViolationHook>>>> =
+    parking_lot::const_rwlock(None);
+
+static PROTOCOL_CHECKS: AtomicBool = AtomicBool::new(cfg!(debug_assertions));
+
+/// A violation of the grammar `on_subscribe on_next* (on_error | on_completed)?` or of the
+/// demand of a [`Subscriber`], as detected by [`SafeObserver`] and [`SafeSubscriber`].
+///
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+/// [`SafeObserver`]: ../observer/struct.SafeObserver.html
+/// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProtocolViolation {
+    /// The given signal was emitted after `on_error` or `on_completed`. The signal is dropped.
+    SignalAfterTermination { signal: &'static str },
+    /// `on_next` was called before a previous call of `on_next` completed. As signals take
+    /// `&mut self`, this is only possible if the future of the previous call was dropped.
+    ConcurrentOnNext,
+    /// `on_next` was called on a [`Subscriber`] without outstanding demand.
+    ///
+    /// [`Subscriber`]: ../core/trait.Subscriber.html
+    OnNextWithoutDemand,
+}
+
+impl fmt::Display for ProtocolViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SignalAfterTermination { signal } => {
+                write!(f, "`{}` was emitted after termination", signal)
+            }
+            Self::ConcurrentOnNext => write!(f, "`on_next` was emitted concurrently"),
+            Self::OnNextWithoutDemand => write!(f, "`on_next` was emitted without demand"),
+        }
+    }
+}
+
+/// Sets the hook which is called for each [`ProtocolViolation`] detected by [`SafeObserver`]
+/// and [`SafeSubscriber`]. Without a hook, violations are ignored apart from dropping signals
+/// after termination. Tests may e.g. panic in the hook to catch misbehaving operators.
+///
+/// [`ProtocolViolation`]: enum.ProtocolViolation.html
+/// [`SafeObserver`]: ../observer/struct.SafeObserver.html
+/// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
+pub fn set_on_protocol_violation<Hook>(hook: Hook)
+where
+    Hook: Fn(&ProtocolViolation) + Send + Sync + 'static,
+{
+    let hook: Box<ProtocolViolationHook> = Box::new(hook);
+    *ON_PROTOCOL_VIOLATION.write() = Some(Arc::new(hook));
+}
+
+/// Removes the hook set via [`set_on_protocol_violation`].
+///
+/// [`set_on_protocol_violation`]: fn.set_on_protocol_violation.html
+pub fn reset_on_protocol_violation() {
+    *ON_PROTOCOL_VIOLATION.write() = None;
+}
+
+/// Calls the hook set via [`set_on_protocol_violation`], if any.
+///
+/// [`set_on_protocol_violation`]: fn.set_on_protocol_violation.html
+pub fn on_protocol_violation(violation: ProtocolViolation) {
+    // The hook is cloned out of the lock, so that it may set or reset hooks itself.
+    let hook = ON_PROTOCOL_VIOLATION.read().clone();
+    if let Some(hook) = hook {
+        hook(&violation);
+    }
+}
+
+/// Enables or disables the checks of [`SafeObserver`] and [`SafeSubscriber`] created from now
+/// on. If disabled, they forward all signals unchanged. The checks are enabled by default if
+/// `debug_assertions` are enabled.
+///
+/// [`SafeObserver`]: ../observer/struct.SafeObserver.html
+/// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
+pub fn set_protocol_checks(enabled: bool) {
+    PROTOCOL_CHECKS.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether the checks of [`SafeObserver`] and [`SafeSubscriber`] are enabled, see
+/// [`set_protocol_checks`].
+///
+/// [`SafeObserver`]: ../observer/struct.SafeObserver.html
+/// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
+/// [`set_protocol_checks`]: fn.set_protocol_checks.html
+pub fn protocol_checks_enabled() -> bool {
+    PROTOCOL_CHECKS.load(Ordering::Relaxed)
+}
+
+/// The outstanding demand checked by a [`SafeSubscriber`]. Clones share the same demand, so
+/// that the subscription through which items are requested may add to it, e.g. a
+/// [`LazySubscription`] if the protocol checks are enabled.
+///
+/// [`SafeSubscriber`]: ../subscriber/struct.SafeSubscriber.html
+/// [`LazySubscription`]: ../subscription/struct.LazySubscription.html
+#[derive(Clone)]
+pub struct Demand {
+    requested: Arc<parking_lot::Mutex<usize>>,
+}
+
+impl Default for Demand {
+    fn default() -> Self {
+        Self {
+            requested: Arc::new(parking_lot::Mutex::new(0)),
+        }
+    }
+}
+
+impl Demand {
+    /// Adds `count` requested items. The demand saturates at `usize::MAX`, i.e. unbounded
+    /// demand.
+    pub fn add(&self, count: usize) {
+        let mut requested = self.requested.lock();
+        *requested = requested.saturating_add(count);
+    }
+
+    // Returns whether there was demand for an item, consuming it.
+    pub(crate) fn take(&self) -> bool {
+        let mut requested = self.requested.lock();
+        match *requested {
+            0 => false,
+            // Unbounded demand is never decremented.
+            usize::MAX => true,
+            _ => {
+                *requested -= 1;
+                true
+            }
+        }
+    }
+}
+
+// The grammar checks shared by SafeObserver and SafeSubscriber.
+pub(crate) struct ProtocolState {
+    enabled: bool,
+    terminated: bool,
+    in_on_next: bool,
+}
+
+impl ProtocolState {
+    pub(crate) fn new() -> Self {
+        Self {
+            enabled: protocol_checks_enabled(),
+            terminated: false,
+            in_on_next: false,
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    // Returns whether the item may be forwarded. If so, end_on_next must be called once
+    // it was forwarded.
+    pub(crate) fn begin_on_next(&mut self) -> bool {
+        if !self.enabled {
+            return true;
+        }
+        if self.terminated {
+            let signal = "on_next";
+            on_protocol_violation(ProtocolViolation::SignalAfterTermination { signal });
+            return false;
+        }
+        if self.in_on_next {
+            on_protocol_violation(ProtocolViolation::ConcurrentOnNext);
+        }
+        self.in_on_next = true;
+        true
+    }
+
+    pub(crate) fn end_on_next(&mut self) {
+        self.in_on_next = false;
+    }
+
+    // Returns whether the terminal signal may be forwarded.
+    pub(crate) fn terminate(&mut self, signal: &'static str) -> bool {
+        if !self.enabled {
+            return true;
+        }
+        if self.terminated {
+            on_protocol_violation(ProtocolViolation::SignalAfterTermination { signal });
+            return false;
+        }
+        self.terminated = true;
+        true
+    }
+}
+
+// The hook is set once for all tests, as tests setting their own hook would miss
+// violations when run in parallel. Each violation is recorded with the thread it was detected
+// on, so that a test only sees the violations of the signals it emitted on its own thread.
+#[cfg(test)]
+pub(crate) struct TestViolations {
+    violations: Arc<parking_lot::Mutex<Vec<(std::thread::ThreadId, ProtocolViolation)>>>,
+    // The violations recorded before the test started, e.g. by a previous test on the thread.
+    skip: usize,
+}
+
+#[cfg(test)]
+impl TestViolations {
+    pub(crate) fn on_current_thread(&self) -> Vec<ProtocolViolation> {
+        let thread = std::thread::current().id();
+        self.violations.lock()[self.skip..]
+            .iter()
+            .filter(|(id, _)| *id == thread)
+            .map(|(_, violation)| *violation)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn test_violations() -> TestViolations {
+    type Violations = Arc<parking_lot::Mutex<Vec<(std::thread::ThreadId, ProtocolViolation)>>>;
+    static TEST_VIOLATIONS: parking_lot::Mutex<Option<Violations>> = parking_lot::const_mutex(None);
+    let violations = TEST_VIOLATIONS
+        .lock()
+        .get_or_insert_with(|| {
+            let violations: Violations = Arc::new(parking_lot::Mutex::new(vec![]));
+            let violations_clone = violations.clone();
+            set_on_protocol_violation(move |violation| {
+                let thread = std::thread::current().id();
+                violations_clone.lock().push((thread, *violation))
+            });
+            violations
+        })
+        .clone();
+    let skip = violations.lock().len();
+    TestViolations { violations, skip }
+}
@@ -0,0 +1,87 @@
+use futures::future::{BoxFuture, Either};
+use futures::FutureExt;
+use std::future::Future;
+#[chronobreak]
+use std::sync::Arc;
+
+type ScheduleHook =
+    dyn Fn(BoxFuture<'static, ()>) -> BoxFuture<'static, ()> + Send + Sync + 'static;
+
+static ON_SCHEDULE: parking_lot::RwLock<Option<Arc<Box<ScheduleHook>>>> =
+    parking_lot::const_rwlock(None);
+
+/// Sets the hook which decorates each future passed to [`Scheduler::schedule`] of the
+/// schedulers in [`scheduler`], e.g. to propagate context across threads or to measure the
+/// time spent in scheduled tasks.
+///
+/// [`Scheduler::schedule`]: ../core/trait.Scheduler.html#tymethod.schedule
+/// [`scheduler`]: ../scheduler/index.html
+pub fn set_on_schedule<Hook>(hook: Hook)
+where
+    Hook: Fn(BoxFuture<'static, ()>) -> BoxFuture<'static, ()> + Send + Sync + 'static,
+{
+    let hook: Box<ScheduleHook> = Box::new(hook);
+    *ON_SCHEDULE.write() = Some(Arc::new(hook));
+}
+
+/// Removes the hook set via [`set_on_schedule`].
+///
+/// [`set_on_schedule`]: fn.set_on_schedule.html
+pub fn reset_on_schedule() {
+    *ON_SCHEDULE.write() = None;
+}
+
+/// Decorates the given future with the hook set via [`set_on_schedule`]. The future is only
+/// boxed if a hook is set. Schedulers defined outside of kled may call this in their
+/// implementation of [`Scheduler::schedule`] to support the hook as well.
+///
+/// [`set_on_schedule`]: fn.set_on_schedule.html
+/// [`Scheduler::schedule`]: ../core/trait.Scheduler.html#tymethod.schedule
+pub fn on_schedule<Fut>(future: Fut) -> Either<Fut, BoxFuture<'static, ()>>
+where
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let hook = ON_SCHEDULE.read().clone();
+    match hook {
+        Some(hook) => Either::Right(hook(future.boxed())),
+        None => Either::Left(future),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hooks;
+    use crate::prelude::*;
+    use crate::scheduler;
+    use futures::FutureExt;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn on_schedule() {
+        let decorated = Arc::new(AtomicUsize::new(0));
+        let decorated_clone = decorated.clone();
+        hooks::set_on_schedule(move |future| {
+            let decorated = decorated_clone.clone();
+            async move {
+                decorated.fetch_add(1, Ordering::SeqCst);
+                future.await
+            }
+            .boxed()
+        });
+        let executed = Arc::new(AtomicUsize::new(0));
+        let executed_clone = executed.clone();
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        scheduler.schedule(async move {
+            executed_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        scheduler.join();
+        hooks::reset_on_schedule();
+
+        assert_eq!(executed.load(Ordering::SeqCst), 1);
+        // Other tests may schedule futures concurrently.
+        assert!(decorated.load(Ordering::SeqCst) >= 1);
+    }
+}
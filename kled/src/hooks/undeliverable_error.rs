@@ -0,0 +1,148 @@
+use crate::hooks::AssemblyTrace;
+use std::any::{self, Any};
+use std::fmt;
+#[chronobreak]
+use std::sync::Arc;
+
+type UndeliverableErrorHandler = dyn Fn(UndeliverableError) + Send + Sync + 'static;
+
+static UNDELIVERABLE_ERROR_HANDLER: parking_lot::RwLock<
+    Option<Arc<Box<UndeliverableErrorHandler>>>,
+> = parking_lot::const_rwlock(None);
+
+/// An error which could not be delivered because the subscriber it was emitted to has no
+/// error handler, e.g. one created via `subscribe_next`. The original error may be retrieved
+/// via [`downcast`] or [`downcast_ref`].
+///
+/// [`downcast`]: #method.downcast
+/// [`downcast_ref`]: #method.downcast_ref
+pub struct UndeliverableError {
+    error: Box<dyn Any + Send>,
+    type_name: &'static str,
+    assembly: AssemblyTrace,
+}
+
+impl UndeliverableError {
+    fn new<Error>(error: Error) -> Self
+    where
+        Error: Send + 'static,
+    {
+        Self {
+            error: Box::new(error),
+            type_name: any::type_name::<Error>(),
+            assembly: AssemblyTrace::current(),
+        }
+    }
+
+    /// Returns the operators of the pipeline which emitted the error, see [`AssemblyTrace`].
+    ///
+    /// [`AssemblyTrace`]: struct.AssemblyTrace.html
+    pub fn assembly(&self) -> &AssemblyTrace {
+        &self.assembly
+    }
+
+    /// Returns the name of the type of the original error.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the original error if it is of type `Error`.
+    pub fn downcast<Error>(self) -> Result<Error, Self>
+    where
+        Error: 'static,
+    {
+        let (type_name, assembly) = (self.type_name, self.assembly);
+        self.error
+            .downcast()
+            .map(|error| *error)
+            .map_err(|error| Self {
+                error,
+                type_name,
+                assembly,
+            })
+    }
+
+    /// Returns a reference to the original error if it is of type `Error`.
+    pub fn downcast_ref<Error>(&self) -> Option<&Error>
+    where
+        Error: 'static,
+    {
+        self.error.downcast_ref()
+    }
+}
+
+impl fmt::Debug for UndeliverableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UndeliverableError")
+            .field("type_name", &self.type_name)
+            .field("assembly", &self.assembly)
+            .finish()
+    }
+}
+
+/// Sets the handler which is called with errors that could not be delivered, see
+/// [`UndeliverableError`]. Without a handler, such errors cause a panic.
+///
+/// [`UndeliverableError`]: struct.UndeliverableError.html
+pub fn set_undeliverable_error_handler<Handler>(handler: Handler)
+where
+    Handler: Fn(UndeliverableError) + Send + Sync + 'static,
+{
+    let handler: Box<UndeliverableErrorHandler> = Box::new(handler);
+    *UNDELIVERABLE_ERROR_HANDLER.write() = Some(Arc::new(handler));
+}
+
+/// Removes the handler set via [`set_undeliverable_error_handler`].
+///
+/// [`set_undeliverable_error_handler`]: fn.set_undeliverable_error_handler.html
+pub fn reset_undeliverable_error_handler() {
+    *UNDELIVERABLE_ERROR_HANDLER.write() = None;
+}
+
+/// Passes the given error to the handler set via [`set_undeliverable_error_handler`].
+///
+/// # Panics
+///
+/// Panics if no handler is set.
+///
+/// [`set_undeliverable_error_handler`]: fn.set_undeliverable_error_handler.html
+pub fn on_undeliverable_error<Error>(error: Error)
+where
+    Error: Send + 'static,
+{
+    let handler = UNDELIVERABLE_ERROR_HANDLER.read().clone();
+    match handler {
+        Some(handler) => handler(UndeliverableError::new(error)),
+        None => panic!(
+            "undeliverable error of type `{}`: subscribe with an error handler or set one via \
+             `kled::hooks::set_undeliverable_error_handler`",
+            any::type_name::<Error>()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hooks;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn undeliverable_error_handler() {
+        let errors = Arc::new(Mutex::new(vec![]));
+        let errors_clone = errors.clone();
+        hooks::set_undeliverable_error_handler(move |error| {
+            // Other tests may emit undeliverable errors concurrently.
+            if let Ok(error) = error.downcast::<flow::Error<usize>>() {
+                errors_clone.lock().unwrap().push(error);
+            }
+        });
+        let test_flow = flow::TestFlow::default().annotate_item_type(());
+        test_flow.clone().subscribe_next(|_| {}).await;
+        test_flow.emit_error(7usize).await;
+        hooks::reset_undeliverable_error_handler();
+
+        assert_eq!(*errors.lock().unwrap(), vec![flow::Error::Upstream(7)]);
+    }
+}
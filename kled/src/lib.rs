@@ -4,6 +4,9 @@
 //!
 #![deny(broken_intra_doc_links)]
 
+// Allows the code generated by kled_derive to refer to this crate as `::kled`.
+extern crate self as kled;
+
 #[allow(unused_imports)]
 #[macro_use(chronobreak)]
 extern crate chronobreak;
@@ -12,6 +15,13 @@ extern crate derive_new;
 #[macro_use]
 extern crate kled_derive;
 
+// Used by the code generated by kled_derive, so that dependent crates need not depend on
+// async-trait and derive-new themselves.
+#[doc(hidden)]
+pub use async_trait;
+#[doc(hidden)]
+pub use derive_new;
+
 #[derive(Copy, Clone)]
 pub enum Never {}
 
@@ -27,12 +37,19 @@ macro_rules! reexport_all {
 pub mod cancellable;
 pub mod core;
 pub mod flow;
+pub mod hooks;
+pub mod metrics;
 pub mod observable;
 pub mod observer;
+pub mod operators;
+pub mod parallel;
 pub mod scheduler;
 pub mod subject;
 pub mod subscriber;
 pub mod subscription;
 
+#[cfg(all(test, feature = "tracing"))]
+mod tracing_recorder;
+
 #[doc(hidden)]
 pub mod prelude;
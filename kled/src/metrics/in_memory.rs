@@ -0,0 +1,114 @@
+use super::Metrics;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+#[chronobreak]
+use std::sync::Arc;
+
+type Key = (&'static str, &'static str);
+
+/// A [`Metrics`] implementation which keeps the reported metrics in memory, e.g. for tests or
+/// to expose them via a custom endpoint. Clones share the same metrics, so a clone may be
+/// passed to [`set_metrics`] while the original is used to read the metrics.
+///
+/// [`Metrics`]: trait.Metrics.html
+/// [`set_metrics`]: fn.set_metrics.html
+pub struct InMemoryMetrics {
+    data: Arc<Data>,
+}
+
+#[derive(Default)]
+struct Data {
+    counters: Mutex<HashMap<Key, u64>>,
+    gauges: Mutex<HashMap<Key, i64>>,
+}
+
+impl InMemoryMetrics {
+    /// Returns the value of the counter identified by `name` and `label`, or `0` if it was
+    /// never incremented.
+    pub fn counter(&self, name: &'static str, label: &'static str) -> u64 {
+        self.data
+            .counters
+            .lock()
+            .get(&(name, label))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the value of the gauge identified by `name` and `label`, or `0` if it was
+    /// never adjusted.
+    pub fn gauge(&self, name: &'static str, label: &'static str) -> i64 {
+        self.data
+            .gauges
+            .lock()
+            .get(&(name, label))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl Default for InMemoryMetrics {
+    fn default() -> Self {
+        Self {
+            data: Arc::new(Data::default()),
+        }
+    }
+}
+
+impl Clone for InMemoryMetrics {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl Metrics for InMemoryMetrics {
+    fn increment_counter(&self, name: &'static str, label: &'static str, value: u64) {
+        let mut counters = self.data.counters.lock();
+        let counter = counters.entry((name, label)).or_insert(0);
+        *counter = counter.saturating_add(value);
+    }
+
+    fn adjust_gauge(&self, name: &'static str, label: &'static str, delta: i64) {
+        let mut gauges = self.data.gauges.lock();
+        let gauge = gauges.entry((name, label)).or_insert(0);
+        *gauge = gauge.saturating_add(delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metrics::*;
+
+    #[async_std::test]
+    async fn counters_and_gauges() {
+        let metrics = InMemoryMetrics::default();
+        let clone = metrics.clone();
+        clone.increment_counter("items", "a", 2);
+        clone.increment_counter("items", "a", 3);
+        clone.increment_counter("items", "b", 1);
+        clone.adjust_gauge("depth", "a", 4);
+        clone.adjust_gauge("depth", "a", -1);
+
+        assert_eq!(metrics.counter("items", "a"), 5);
+        assert_eq!(metrics.counter("items", "b"), 1);
+        assert_eq!(metrics.counter("items", "c"), 0);
+        assert_eq!(metrics.gauge("depth", "a"), 3);
+        assert_eq!(metrics.gauge("depth", "b"), 0);
+    }
+
+    #[async_std::test]
+    async fn saturates() {
+        let metrics = InMemoryMetrics::default();
+        metrics.increment_counter("items", "a", u64::MAX);
+        metrics.increment_counter("items", "a", 1);
+        metrics.adjust_gauge("depth", "a", i64::MAX);
+        metrics.adjust_gauge("depth", "a", 1);
+        metrics.adjust_gauge("depth", "b", i64::MIN);
+        metrics.adjust_gauge("depth", "b", -1);
+
+        assert_eq!(metrics.counter("items", "a"), u64::MAX);
+        assert_eq!(metrics.gauge("depth", "a"), i64::MAX);
+        assert_eq!(metrics.gauge("depth", "b"), i64::MIN);
+    }
+}
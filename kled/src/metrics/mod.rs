@@ -0,0 +1,31 @@
+//! Counters and gauges reported by kled's operators and schedulers, without depending on a
+//! particular metrics backend.
+//!
+//! Metrics are reported to the [`Metrics`] implementation set via [`set_metrics`], e.g. the
+//! [`InMemoryMetrics`] or an adapter to a metrics backend. If none is set, reporting does
+//! nothing. Each metric is identified by a name and a label:
+//!
+//! | Name | Kind | Label | Description |
+//! |------|------|-------|-------------|
+//! | `items` | counter | the label given to `metered` | items emitted |
+//! | `errors` | counter | the label given to `metered` | errors emitted |
+//! | `completions` | counter | the label given to `metered` | completions emitted |
+//! | `cancellations` | counter | the label given to `metered` | cancellations by the downstream |
+//! | `requested` | counter | the label given to `metered` | items requested by the downstream of a `Flow` |
+//! | `queue_depth` | gauge | `on_backpressure_buffer` | items buffered |
+//! | `dropped` | counter | `on_backpressure_drop`, `on_backpressure_latest` | items dropped |
+//! | `outstanding_demand` | gauge | `arc_subscription` | requested items not yet taken from an [`ArcSubscriptionStub`], excluding unbounded demand |
+//! | `active_tasks` | gauge | `thread_pool`, `new_thread` | scheduled futures which did not complete yet |
+//!
+//! Gauges are adjusted by each instance of an operator or scheduler, so that their value is
+//! the sum over all instances.
+//!
+//! [`Metrics`]: trait.Metrics.html
+//! [`set_metrics`]: fn.set_metrics.html
+//! [`InMemoryMetrics`]: struct.InMemoryMetrics.html
+//! [`ArcSubscriptionStub`]: ../subscription/struct.ArcSubscriptionStub.html
+
+reexport_all! {
+    mod in_memory;
+    mod registry;
+}
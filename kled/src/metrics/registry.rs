@@ -0,0 +1,71 @@
+#[chronobreak]
+use std::sync::Arc;
+
+static METRICS: parking_lot::RwLock<Option<Arc<Box<dyn Metrics>>>> =
+    parking_lot::const_rwlock(None);
+
+/// A sink for the metrics reported by kled, see the [module documentation](index.html).
+pub trait Metrics: Send + Sync + 'static {
+    /// Adds `value` to the counter identified by `name` and `label`.
+    fn increment_counter(&self, name: &'static str, label: &'static str, value: u64);
+
+    /// Adds `delta` to the gauge identified by `name` and `label`.
+    fn adjust_gauge(&self, name: &'static str, label: &'static str, delta: i64);
+}
+
+/// Sets the [`Metrics`] to which all metrics are reported from now on.
+///
+/// [`Metrics`]: trait.Metrics.html
+pub fn set_metrics<M>(metrics: M)
+where
+    M: Metrics,
+{
+    let metrics: Box<dyn Metrics> = Box::new(metrics);
+    *METRICS.write() = Some(Arc::new(metrics));
+}
+
+/// Removes the [`Metrics`] set via [`set_metrics`].
+///
+/// [`Metrics`]: trait.Metrics.html
+/// [`set_metrics`]: fn.set_metrics.html
+pub fn reset_metrics() {
+    *METRICS.write() = None;
+}
+
+/// Reports an increment of a counter to the [`Metrics`] set via [`set_metrics`], if any.
+/// Operators defined outside of kled may call this to report their own metrics.
+///
+/// [`Metrics`]: trait.Metrics.html
+/// [`set_metrics`]: fn.set_metrics.html
+pub fn increment_counter(name: &'static str, label: &'static str, value: u64) {
+    if let Some(metrics) = METRICS.read().as_ref() {
+        metrics.increment_counter(name, label, value);
+    }
+}
+
+/// Reports an adjustment of a gauge to the [`Metrics`] set via [`set_metrics`], if any.
+/// Operators defined outside of kled may call this to report their own metrics.
+///
+/// [`Metrics`]: trait.Metrics.html
+/// [`set_metrics`]: fn.set_metrics.html
+pub fn adjust_gauge(name: &'static str, label: &'static str, delta: i64) {
+    if let Some(metrics) = METRICS.read().as_ref() {
+        metrics.adjust_gauge(name, label, delta);
+    }
+}
+
+// The metrics are set once for all tests, as tests setting their own metrics would steal
+// each other's reports when run in parallel. Tests should use labels unique to them.
+#[cfg(test)]
+pub(crate) fn test_metrics() -> super::InMemoryMetrics {
+    static TEST_METRICS: parking_lot::Mutex<Option<super::InMemoryMetrics>> =
+        parking_lot::const_mutex(None);
+    TEST_METRICS
+        .lock()
+        .get_or_insert_with(|| {
+            let metrics = super::InMemoryMetrics::default();
+            set_metrics(metrics.clone());
+            metrics
+        })
+        .clone()
+}
@@ -0,0 +1,144 @@
+use crate::cancellable::*;
+use crate::core;
+use async_trait::async_trait;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+type BoxedObserver<Item, Error> =
+    Box<dyn core::Observer<BoxCancellable, Item, Error> + Send + 'static>;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+// The generic subscribe method of Observable is not object-safe, therefore the boxed
+// observable is subscribed via this trait which takes the observer as a trait object.
+trait DynObservable<Item, Error>: Send {
+    fn subscribe_dyn(self: Box<Self>, observer: BoxedObserver<Item, Error>) -> BoxFuture;
+}
+
+struct Erased<Observable, Cancellable> {
+    observable: Observable,
+    phantom: PhantomData<Cancellable>,
+}
+
+impl<Observable, Cancellable, Item, Error> DynObservable<Item, Error>
+    for Erased<Observable, Cancellable>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn subscribe_dyn(self: Box<Self>, observer: BoxedObserver<Item, Error>) -> BoxFuture {
+        Box::pin(async move {
+            self.observable.subscribe(BoxObservableObserver { observer }).await
+        })
+    }
+}
+
+/// A type-erased [`Observable`], which hides both the type of the upstream observable and
+/// the type of its cancellable. This allows to store observables in struct fields or to
+/// return them from trait methods.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub struct BoxObservable<Item, Error> {
+    inner: Box<dyn DynObservable<Item, Error>>,
+}
+
+impl<Item, Error> BoxObservable<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    pub fn new<Observable, Cancellable>(observable: Observable) -> Self
+    where
+        Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+        Cancellable: core::Cancellable + Send + Sync + 'static,
+    {
+        Self {
+            inner: Box::new(Erased {
+                observable,
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl<Item, Error> core::Observable<BoxCancellable, Item, Error> for BoxObservable<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<BoxCancellable, Item, Error> + Send + 'static,
+    {
+        self.inner.subscribe_dyn(Box::new(observer)).await
+    }
+}
+
+struct BoxObservableObserver<Item, Error> {
+    observer: BoxedObserver<Item, Error>,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Observer<Cancellable, Item, Error>
+    for BoxObservableObserver<Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer
+            .on_subscribe(BoxCancellable::new(cancellable))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::Never;
+
+    fn boxed_range(count: usize) -> BoxObservable<usize, Never> {
+        observable::range(0, count).map(|i| i * 2).boxed()
+    }
+
+    #[async_std::test]
+    async fn basic() {
+        let test_observer = TestObserver::default();
+        boxed_range(3).subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 2, 4]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(0)
+            .annotate_error_type(());
+        test_observable
+            .clone()
+            .boxed()
+            .subscribe(test_observer.clone()).await;
+        test_observer.cancel().await;
+        assert!(test_observable.is_cancelled().await);
+    }
+}
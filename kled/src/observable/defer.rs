@@ -0,0 +1,68 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct ObservableDefer<F> {
+    factory: F,
+}
+
+#[async_trait]
+impl<F, Observable, Cancellable, Item, Error> core::Observable<Cancellable, Item, Error>
+    for ObservableDefer<F>
+where
+    F: FnOnce() -> Observable + Send,
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    {
+        (self.factory)().subscribe(observer).await;
+    }
+}
+
+/// Returns an [`Observable`] that calls `factory` for each subscribing [`Observer`] and
+/// subscribes it to the `Observable` returned by `factory`.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+/// [`Observer`]: ../core/trait.Observer.html
+pub fn defer<F, Observable, Cancellable, Item, Error>(factory: F) -> ObservableDefer<F>
+where
+    F: FnOnce() -> Observable + Send,
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    ObservableDefer::new(factory)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn defer() {
+        let test_observer = TestObserver::default();
+        let called = Arc::new(AtomicBool::new(false));
+        let called2 = called.clone();
+        let observable = observable::defer(move || {
+            called2.store(true, Ordering::Relaxed);
+            vec![0, 1, 2].into_observable()
+        });
+        assert!(!called.load(Ordering::Relaxed));
+        observable.subscribe(test_observer.clone()).await;
+        assert!(called.load(Ordering::Relaxed));
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct ObservableEmpty<Item, Error> {
+    phantom: PhantomData<(Item, Error)>,
+}
+
+#[async_trait]
+impl<Item, Error> core::Observable<ArcCancellable, Item, Error> for ObservableEmpty<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<ArcCancellable, Item, Error> + Send + 'static,
+    {
+        let mut emitter = observable::Emitter::from(observer).await;
+        if !emitter.is_cancelled() {
+            emitter.on_completed().await;
+        }
+    }
+}
+
+/// Returns an [`Observable`] that emits no items and completes immediately.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn empty<Item, Error>() -> ObservableEmpty<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    ObservableEmpty::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn empty() {
+        let test_observer = TestObserver::default();
+        observable::empty::<i32, ()>()
+            .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![]);
+    }
+}
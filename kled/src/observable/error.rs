@@ -0,0 +1,56 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct ObservableError<Item, Error> {
+    error: Error,
+    phantom: PhantomData<Item>,
+}
+
+#[async_trait]
+impl<Item, Error> core::Observable<ArcCancellable, Item, Error> for ObservableError<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<ArcCancellable, Item, Error> + Send + 'static,
+    {
+        let mut emitter = observable::Emitter::from(observer).await;
+        if !emitter.is_cancelled() {
+            emitter.on_error(self.error).await;
+        }
+    }
+}
+
+/// Returns an [`Observable`] that emits no items and terminates immediately with `error`.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn error<Item, Error>(error: Error) -> ObservableError<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    ObservableError::new(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn error() {
+        let test_observer = TestObserver::default();
+        observable::error::<i32, _>(42)
+            .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, vec![]);
+        assert_eq!(test_observer.error().await, Some(42));
+    }
+}
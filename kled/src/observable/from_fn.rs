@@ -0,0 +1,34 @@
+use crate::core::IntoObservable;
+use crate::observable::IntoIterObservable;
+use std::iter::{self, FromFn};
+
+/// Returns an [`Observable`] that emits the items returned by `f` until it returns `None`, at
+/// which point the `Observable` completes.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn from_fn<F, Item>(f: F) -> IntoIterObservable<FromFn<F>>
+where
+    F: FnMut() -> Option<Item> + Send,
+    Item: Send + 'static,
+{
+    iter::from_fn(f).into_observable()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn from_fn() {
+        let test_observer = TestObserver::default();
+        let mut count = 0;
+        observable::from_fn(move || {
+            count += 1;
+            if count <= 3 { Some(count) } else { None }
+        })
+        .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2, 3]);
+    }
+}
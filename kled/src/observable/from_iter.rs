@@ -3,6 +3,7 @@ use crate::core;
 use crate::observable;
 use crate::Never;
 use async_trait::async_trait;
+use futures::future;
 
 #[doc(hidden)]
 pub struct IntoIterObservable<IntoIter> {
@@ -43,6 +44,29 @@ where
             observer.on_completed().await;
         }
     }
+
+    async fn subscribe_raw<Observer>(self, mut observer: Observer)
+    where
+        Self: Sized,
+        Observer:
+            core::RawObserver<ArcCancellable, IntoIter::Item, Never> + Send + 'static,
+    {
+        let stub = ArcCancellableStub::default();
+        future::poll_fn(|cx| observer.poll_ready(cx)).await;
+        observer.start_subscribe(stub.cancellable());
+        for v in self.iterable.into_iter() {
+            future::poll_fn(|cx| observer.poll_ready(cx)).await;
+            if stub.is_cancelled() {
+                return;
+            }
+            observer.start_next(v);
+        }
+        future::poll_fn(|cx| observer.poll_ready(cx)).await;
+        if !stub.is_cancelled() {
+            observer.start_completed();
+            future::poll_fn(|cx| observer.poll_ready(cx)).await;
+        }
+    }
 }
 
 impl<IntoIter> core::IntoObservable<ArcCancellable, IntoIter::Item, Never> for IntoIter
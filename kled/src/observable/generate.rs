@@ -0,0 +1,127 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+/// The emitter passed to the generator function of [`generate`]. The generator may call
+/// `on_next` at most once per invocation and may terminate the `Observable` by calling either
+/// `on_error` or `on_completed`.
+///
+/// [`generate`]: fn.generate.html
+pub struct GenerateEmitter<Item, Error> {
+    item: Option<Item>,
+    terminal: Option<Result<(), Error>>,
+}
+
+impl<Item, Error> Default for GenerateEmitter<Item, Error> {
+    fn default() -> Self {
+        Self {
+            item: None,
+            terminal: None,
+        }
+    }
+}
+
+impl<Item, Error> GenerateEmitter<Item, Error> {
+    pub fn on_next(&mut self, item: Item) {
+        const MSG: &str = "GenerateEmitter::on_next: called more than once per invocation";
+        assert!(self.item.is_none(), "{}", MSG);
+        self.item = Some(item);
+    }
+
+    pub fn on_error(&mut self, error: Error) {
+        self.terminal = Some(Err(error));
+    }
+
+    pub fn on_completed(&mut self) {
+        self.terminal = Some(Ok(()));
+    }
+}
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct ObservableGenerate<State, GeneratorFn, Item, Error> {
+    initial_state: State,
+    generator: GeneratorFn,
+    phantom: PhantomData<(Item, Error)>,
+}
+
+#[async_trait]
+impl<State, GeneratorFn, Item, Error> core::Observable<ArcCancellable, Item, Error>
+    for ObservableGenerate<State, GeneratorFn, Item, Error>
+where
+    State: Send,
+    GeneratorFn: FnMut(&mut State, &mut GenerateEmitter<Item, Error>) + Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<ArcCancellable, Item, Error> + Send + 'static,
+    {
+        let mut state = self.initial_state;
+        let mut generator = self.generator;
+        let mut emitter = observable::Emitter::from(observer).await;
+        while !emitter.is_cancelled() {
+            let mut generate_emitter = GenerateEmitter::default();
+            generator(&mut state, &mut generate_emitter);
+            if let Some(item) = generate_emitter.item {
+                emitter.on_next(item).await;
+            }
+            match generate_emitter.terminal {
+                Some(Ok(())) => {
+                    emitter.on_completed().await;
+                    break;
+                }
+                Some(Err(error)) => {
+                    emitter.on_error(error).await;
+                    break;
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+/// Returns an [`Observable`] that repeatedly calls `generator` with a mutable reference to its
+/// state, which is initialized with `initial_state`, and a [`GenerateEmitter`] until the
+/// generator terminates the `Observable` or it is cancelled.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+/// [`GenerateEmitter`]: struct.GenerateEmitter.html
+pub fn generate<State, GeneratorFn, Item, Error>(
+    initial_state: State,
+    generator: GeneratorFn,
+) -> ObservableGenerate<State, GeneratorFn, Item, Error>
+where
+    State: Send,
+    GeneratorFn: FnMut(&mut State, &mut GenerateEmitter<Item, Error>) + Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    ObservableGenerate::new(initial_state, generator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn generate() {
+        let test_observer = TestObserver::default();
+        observable::generate(0, |state, emitter: &mut GenerateEmitter<_, ()>| {
+            if *state < 3 {
+                emitter.on_next(*state);
+                *state += 1;
+            } else {
+                emitter.on_completed();
+            }
+        })
+        .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::core::IntoObservable;
+use crate::observable::IntoIterObservable;
+use std::iter::{once, Once};
+
+/// Returns an [`Observable`] that emits `item` and completes.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn just<Item>(item: Item) -> IntoIterObservable<Once<Item>>
+where
+    Item: Send + 'static,
+{
+    once(item).into_observable()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn just() {
+        let test_observer = TestObserver::default();
+        observable::just(42)
+            .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![42]);
+    }
+}
@@ -1,10 +1,21 @@
 reexport_all! {
     mod box_emitter;
+    mod box_observable;
     mod create;
+    mod defer;
     mod emitter;
+    mod empty;
+    mod error;
+    mod from_fn;
     mod from_iter;
+    mod generate;
+    mod just;
+    mod never;
+    mod range;
+    mod repeat;
     mod signal;
     mod test_observable;
+    mod using;
 }
 
 pub mod operators;
@@ -0,0 +1,36 @@
+use crate::cancellable::*;
+use crate::core;
+use crate::observable;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct ObservableNever<Item, Error> {
+    phantom: PhantomData<(Item, Error)>,
+}
+
+#[async_trait]
+impl<Item, Error> core::Observable<ArcCancellable, Item, Error> for ObservableNever<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<ArcCancellable, Item, Error> + Send + 'static,
+    {
+        observable::Emitter::from(observer).await;
+    }
+}
+
+/// Returns an [`Observable`] that emits neither items nor a terminal signal.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn never<Item, Error>() -> ObservableNever<Item, Error>
+where
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    ObservableNever::new()
+}
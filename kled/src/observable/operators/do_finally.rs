@@ -0,0 +1,145 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "observable", subscription = "DoFinallyCancellable<Cancellable, FinallyFn>")]
+pub struct DoFinally<FinallyFn>
+where
+    FinallyFn: FnOnce(),
+{
+    finally_fn: FinallyFn,
+}
+
+struct DoFinallyObserver<Observer, FinallyFn> {
+    observer: Observer,
+    finally_fn: Arc<Mutex<Option<FinallyFn>>>,
+}
+
+impl<Observer, FinallyFn> DoFinallyObserver<Observer, FinallyFn> {
+    fn new(observer: Observer, finally_fn: FinallyFn) -> Self {
+        Self {
+            observer,
+            finally_fn: Arc::new(Mutex::new(Some(finally_fn))),
+        }
+    }
+}
+
+async fn run_once<FinallyFn>(finally_fn: &Mutex<Option<FinallyFn>>)
+where
+    FinallyFn: FnOnce(),
+{
+    let finally_fn = finally_fn.lock().await.take();
+    if let Some(finally_fn) = finally_fn {
+        finally_fn();
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, FinallyFn> core::Observer<Cancellable, Item, Error>
+    for DoFinallyObserver<Observer, FinallyFn>
+where
+    Observer: core::Observer<DoFinallyCancellable<Cancellable, FinallyFn>, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    FinallyFn: FnOnce() + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancellable = DoFinallyCancellable::new(cancellable, self.finally_fn.clone());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+        run_once(&self.finally_fn).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+        run_once(&self.finally_fn).await;
+    }
+}
+
+#[derive(new)]
+pub struct DoFinallyCancellable<Upstream, FinallyFn> {
+    upstream: Upstream,
+    finally_fn: Arc<Mutex<Option<FinallyFn>>>,
+}
+
+impl<Upstream, FinallyFn> Clone for DoFinallyCancellable<Upstream, FinallyFn>
+where
+    Upstream: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            finally_fn: self.finally_fn.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, FinallyFn> core::Cancellable for DoFinallyCancellable<Upstream, FinallyFn>
+where
+    Upstream: core::Cancellable + Send + Sync,
+    FinallyFn: FnOnce() + Send,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        run_once(&self.finally_fn).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_finally_completed() {
+        let test_observer = TestObserver::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = count.clone();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .do_finally(move || {
+                count2.fetch_add(1, Ordering::Relaxed);
+            })
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[async_std::test]
+    async fn do_finally_cancelled() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = count.clone();
+        test_observable
+            .clone()
+            .do_finally(move || {
+                count2.fetch_add(1, Ordering::Relaxed);
+            })
+            .subscribe(test_observer.clone()).await;
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+        test_observer.cancel().await;
+
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        assert!(test_observable.is_cancelled().await);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::core;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[operator(type = "observable", subscription = "DoOnCancelCancellable<Cancellable, CancelFn>")]
+pub struct DoOnCancel<CancelFn>
+where
+    CancelFn: FnMut(),
+{
+    cancel_fn: CancelFn,
+}
+
+struct DoOnCancelObserver<Observer, CancelFn> {
+    observer: Observer,
+    cancel_fn: Arc<Mutex<CancelFn>>,
+}
+
+impl<Observer, CancelFn> DoOnCancelObserver<Observer, CancelFn> {
+    fn new(observer: Observer, cancel_fn: CancelFn) -> Self {
+        Self {
+            observer,
+            cancel_fn: Arc::new(Mutex::new(cancel_fn)),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, CancelFn> core::Observer<Cancellable, Item, Error>
+    for DoOnCancelObserver<Observer, CancelFn>
+where
+    Observer: core::Observer<DoOnCancelCancellable<Cancellable, CancelFn>, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CancelFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancellable = DoOnCancelCancellable::new(cancellable, self.cancel_fn.clone());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[derive(new)]
+pub struct DoOnCancelCancellable<Upstream, CancelFn> {
+    upstream: Upstream,
+    cancel_fn: Arc<Mutex<CancelFn>>,
+}
+
+impl<Upstream, CancelFn> Clone for DoOnCancelCancellable<Upstream, CancelFn>
+where
+    Upstream: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            cancel_fn: self.cancel_fn.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, CancelFn> core::Cancellable for DoOnCancelCancellable<Upstream, CancelFn>
+where
+    Upstream: core::Cancellable + Send + Sync,
+    CancelFn: FnMut() + Send,
+{
+    async fn cancel(&self) {
+        (&mut *self.cancel_fn.lock().await)();
+        self.upstream.cancel().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_cancel() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancelled2 = cancelled.clone();
+        test_observable
+            .clone()
+            .do_on_cancel(move || cancelled2.store(true, Ordering::Relaxed))
+            .subscribe(test_observer.clone()).await;
+        assert!(!cancelled.load(Ordering::Relaxed));
+        test_observer.cancel().await;
+
+        assert!(cancelled.load(Ordering::Relaxed));
+        assert!(test_observable.is_cancelled().await);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnCompleted<CompletedFn>
+where
+    CompletedFn: FnMut(),
+{
+    completed_fn: CompletedFn,
+}
+
+#[derive(new)]
+struct DoOnCompletedObserver<Observer, CompletedFn> {
+    observer: Observer,
+    completed_fn: CompletedFn,
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, CompletedFn> core::Observer<Cancellable, Item, Error>
+    for DoOnCompletedObserver<Observer, CompletedFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    CompletedFn: FnMut() + Send,
+{
+    async fn on_completed(&mut self) {
+        (self.completed_fn)();
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_completed() {
+        let test_observer = TestObserver::default();
+        let completed = Arc::new(AtomicBool::new(false));
+        let completed2 = completed.clone();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .do_on_completed(move || completed2.store(true, Ordering::Relaxed))
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert!(completed.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnError<ErrorFn>
+where
+    ErrorFn: FnMut(&Error),
+{
+    error_fn: ErrorFn,
+}
+
+#[derive(new)]
+struct DoOnErrorObserver<Observer, ErrorFn> {
+    observer: Observer,
+    error_fn: ErrorFn,
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, ErrorFn> core::Observer<Cancellable, Item, Error>
+    for DoOnErrorObserver<Observer, ErrorFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ErrorFn: FnMut(&Error) + Send,
+{
+    async fn on_error(&mut self, error: Error) {
+        (self.error_fn)(&error);
+        self.observer.on_error(error).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_error() {
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(());
+        let error = Arc::new(AtomicUsize::new(0));
+        let error2 = error.clone();
+        test_observable
+            .clone()
+            .do_on_error(move |e| {
+                error2.store(*e, Ordering::Relaxed);
+            })
+            .subscribe(test_observer.clone()).await;
+        test_observable.emit_error(42).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.error().await, Some(42));
+        assert_eq!(error.load(Ordering::Relaxed), 42);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnNext<NextFn>
+where
+    NextFn: FnMut(&Item),
+{
+    next_fn: NextFn,
+}
+
+#[derive(new)]
+struct DoOnNextObserver<Observer, NextFn> {
+    observer: Observer,
+    next_fn: NextFn,
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, NextFn> core::Observer<Cancellable, Item, Error>
+    for DoOnNextObserver<Observer, NextFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NextFn: FnMut(&Item) + Send,
+{
+    async fn on_next(&mut self, item: Item) {
+        (self.next_fn)(&item);
+        self.observer.on_next(item).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_next() {
+        let test_observer = TestObserver::default();
+        let sum = Arc::new(AtomicUsize::new(0));
+        let sum2 = sum.clone();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .do_on_next(move |item| {
+                sum2.fetch_add(*item, Ordering::Relaxed);
+            })
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(sum.load(Ordering::Relaxed), 6);
+    }
+}
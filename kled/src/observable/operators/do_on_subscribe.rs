@@ -0,0 +1,57 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct DoOnSubscribe<SubscribeFn>
+where
+    SubscribeFn: FnMut(&Cancellable),
+{
+    subscribe_fn: SubscribeFn,
+}
+
+#[derive(new)]
+struct DoOnSubscribeObserver<Observer, SubscribeFn> {
+    observer: Observer,
+    subscribe_fn: SubscribeFn,
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, SubscribeFn> core::Observer<Cancellable, Item, Error>
+    for DoOnSubscribeObserver<Observer, SubscribeFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    SubscribeFn: FnMut(&Cancellable) + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        (self.subscribe_fn)(&cancellable);
+        self.observer.on_subscribe(cancellable).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn do_on_subscribe() {
+        let test_observer = TestObserver::default();
+        let subscribed = Arc::new(AtomicBool::new(false));
+        let subscribed2 = subscribed.clone();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .do_on_subscribe(move |_| subscribed2.store(true, Ordering::Relaxed))
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert!(subscribed.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::core;
+use async_trait::async_trait;
+use futures::future::{self, Join, Ready};
+use futures::prelude::*;
+use futures::stream::FuturesOrdered;
+
+#[operator(type = "observable")]
+pub struct FilterAsync<Fut, Predicate>
+where
+    Fut: Future<Output = bool> + Send,
+    Predicate: FnMut(&Item) -> Fut + Send,
+{
+    predicate: Predicate,
+    concurrency: usize,
+}
+
+struct FilterAsyncObserver<Observer, Item, Fut, Predicate>
+where
+    Fut: Future,
+{
+    observer: Observer,
+    predicate: Predicate,
+    concurrency: usize,
+    // The most recent items together with the futures deciding whether they are emitted,
+    // which are emitted in order as soon as they are ready. Once `concurrency` of them are
+    // pending, the oldest is awaited.
+    pending: FuturesOrdered<Join<Ready<Item>, Fut>>,
+}
+
+impl<Observer, Item, Fut, Predicate> FilterAsyncObserver<Observer, Item, Fut, Predicate>
+where
+    Fut: Future,
+{
+    fn new(observer: Observer, predicate: Predicate, concurrency: usize) -> Self {
+        Self {
+            observer,
+            predicate,
+            concurrency: concurrency.max(1),
+            pending: FuturesOrdered::new(),
+        }
+    }
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, Fut, Predicate> core::Observer<Cancellable, Item, Error>
+    for FilterAsyncObserver<Observer, Item, Fut, Predicate>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Fut: Future<Output = bool> + Send,
+    Predicate: FnMut(&Item) -> Fut + Send,
+{
+    async fn on_next(&mut self, item: Item) {
+        let keep = (self.predicate)(&item);
+        self.pending
+            .push_back(future::join(future::ready(item), keep));
+        while self.pending.len() >= self.concurrency {
+            if let Some((item, true)) = self.pending.next().await {
+                self.observer.on_next(item).await;
+            }
+        }
+        while let Some(Some((item, keep))) = self.pending.next().now_or_never() {
+            if keep {
+                self.observer.on_next(item).await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.pending = FuturesOrdered::new();
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        while let Some((item, keep)) = self.pending.next().await {
+            if keep {
+                self.observer.on_next(item).await;
+            }
+        }
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use futures::future;
+
+    #[async_std::test]
+    async fn filter_async() {
+        let test_observer = TestObserver::default();
+        (0..10)
+            .into_observable()
+            .filter_async(|a| future::ready(a % 3 == 0), 1)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 3, 6, 9]);
+    }
+
+    #[async_std::test]
+    async fn emits_ready_items() {
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let test_observer = TestObserver::default();
+        test_observable
+            .clone()
+            .filter_async(
+                |a| {
+                    let a = *a;
+                    async move { a % 2 == 1 }
+                },
+                4,
+            )
+            .subscribe(test_observer.clone())
+            .await;
+
+        test_observable.emit_all(0..4).await;
+        assert_eq!(test_observer.items().await, vec![1, 3]);
+        test_observable.emit(5).await;
+        assert_eq!(test_observer.items().await, vec![1, 3, 5]);
+        test_observable.emit_on_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+    }
+}
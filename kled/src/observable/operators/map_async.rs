@@ -0,0 +1,165 @@
+use crate::core;
+use async_trait::async_trait;
+use futures::prelude::*;
+use futures::stream::FuturesOrdered;
+
+#[operator(type = "observable", item = "ItemOut")]
+pub struct MapAsync<ItemOut, Fut, UnaryOp>
+where
+    Fut: Future<Output = ItemOut> + Send,
+    UnaryOp: FnMut(Item) -> Fut + Send,
+{
+    unary_op: UnaryOp,
+    concurrency: usize,
+}
+
+struct MapAsyncObserver<Observer, Fut, UnaryOp>
+where
+    Fut: Future,
+{
+    observer: Observer,
+    unary_op: UnaryOp,
+    concurrency: usize,
+    // The futures created for the most recent items, whose results are emitted in order as
+    // soon as they are ready. Once `concurrency` of them are pending, the oldest is awaited.
+    pending: FuturesOrdered<Fut>,
+}
+
+impl<Observer, Fut, UnaryOp> MapAsyncObserver<Observer, Fut, UnaryOp>
+where
+    Fut: Future,
+{
+    fn new(observer: Observer, unary_op: UnaryOp, concurrency: usize) -> Self {
+        Self {
+            observer,
+            unary_op,
+            concurrency: concurrency.max(1),
+            pending: FuturesOrdered::new(),
+        }
+    }
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, ItemIn, Observer, ItemOut, Error, Fut, UnaryOp>
+    core::Observer<Cancellable, ItemIn, Error> for MapAsyncObserver<Observer, Fut, UnaryOp>
+where
+    Observer: core::Observer<Cancellable, ItemOut, Error> + Send,
+    Cancellable: Send + 'static,
+    ItemIn: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+    Fut: Future<Output = ItemOut> + Send,
+    UnaryOp: FnMut(ItemIn) -> Fut + Send,
+{
+    async fn on_next(&mut self, item: ItemIn) {
+        self.pending.push_back((self.unary_op)(item));
+        while self.pending.len() >= self.concurrency {
+            if let Some(item) = self.pending.next().await {
+                self.observer.on_next(item).await;
+            }
+        }
+        while let Some(Some(item)) = self.pending.next().now_or_never() {
+            self.observer.on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.pending = FuturesOrdered::new();
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        while let Some(item) = self.pending.next().await {
+            self.observer.on_next(item).await;
+        }
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use futures::future;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn map_async() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .map_async(|a| async move { a + 1 }, 1)
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2, 3, 4]);
+    }
+
+    #[async_std::test]
+    async fn emits_ready_results() {
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let test_observer = TestObserver::default();
+        test_observable
+            .clone()
+            .map_async(|a| async move { a * 2 }, 3)
+            .subscribe(test_observer.clone())
+            .await;
+
+        test_observable.emit_all(0..2).await;
+        assert_eq!(test_observer.items().await, vec![0, 2]);
+        test_observable.emit(2).await;
+        assert_eq!(test_observer.items().await, vec![0, 2, 4]);
+        test_observable.emit_on_completed().await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+    }
+
+    #[async_std::test]
+    async fn error_drops_pending() {
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let test_observer = TestObserver::default();
+        test_observable
+            .clone()
+            .map_async(
+                |a| async move {
+                    if a == 2 {
+                        future::pending::<()>().await;
+                    }
+                    a * 2
+                },
+                2,
+            )
+            .subscribe(test_observer.clone())
+            .await;
+
+        test_observable.emit_all(0..3).await;
+        test_observable.emit_error(()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, vec![0, 2]);
+    }
+
+    #[async_std::test]
+    async fn for_each_async() {
+        let sum = Arc::new(AtomicUsize::new(0));
+        let sum_clone = sum.clone();
+        (0..4)
+            .into_observable()
+            .for_each_async(
+                move |a| {
+                    let sum = sum_clone.clone();
+                    async move {
+                        sum.fetch_add(a, Ordering::SeqCst);
+                    }
+                },
+                2,
+            )
+            .await;
+
+        assert_eq!(sum.load(Ordering::SeqCst), 6);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{core, metrics};
+use async_trait::async_trait;
+
+#[operator(type = "observable", subscription = "MeteredCancellable<Cancellable>")]
+pub struct Metered {
+    label: &'static str,
+}
+
+#[derive(new)]
+struct MeteredObserver<Observer> {
+    observer: Observer,
+    label: &'static str,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer> core::Observer<Cancellable, Item, Error>
+    for MeteredObserver<Observer>
+where
+    Observer: core::Observer<MeteredCancellable<Cancellable>, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancellable = MeteredCancellable::new(cancellable, self.label);
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        metrics::increment_counter("items", self.label, 1);
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        metrics::increment_counter("errors", self.label, 1);
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        metrics::increment_counter("completions", self.label, 1);
+        self.observer.on_completed().await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct MeteredCancellable<Upstream> {
+    upstream: Upstream,
+    label: &'static str,
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for MeteredCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync,
+{
+    async fn cancel(&self) {
+        metrics::increment_counter("cancellations", self.label, 1);
+        self.upstream.cancel().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metrics;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn metered() {
+        let metrics = metrics::test_metrics();
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .metered("observable_metered")
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(metrics.counter("items", "observable_metered"), 4);
+        assert_eq!(metrics.counter("completions", "observable_metered"), 1);
+        assert_eq!(metrics.counter("errors", "observable_metered"), 0);
+    }
+
+    #[async_std::test]
+    async fn counts_errors_and_cancellations() {
+        let metrics = metrics::test_metrics();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_item_type(());
+        test_observable
+            .clone()
+            .metered("observable_metered_error")
+            .subscribe(test_observer.clone()).await;
+        test_observable.emit_error(()).await;
+        test_observer.cancel().await;
+
+        assert_eq!(metrics.counter("errors", "observable_metered_error"), 1);
+        assert_eq!(metrics.counter("cancellations", "observable_metered_error"), 1);
+    }
+}
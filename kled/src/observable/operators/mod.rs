@@ -1,8 +1,23 @@
 reexport_all! {
-    mod dematerialize;
-    mod map;
-    mod materialize;
+    mod do_finally;
+    mod do_on_cancel;
+    mod do_on_completed;
+    mod do_on_error;
+    mod do_on_next;
+    mod do_on_subscribe;
+    mod filter_async;
+    mod map_async;
+    mod metered;
     mod observe_on;
-    mod scan;
-    mod subscribe_on;
+    mod scan_async;
+    mod tap;
+    mod to_flow;
+    mod unsubscribe_on;
 }
+
+#[cfg(feature = "tracing")]
+mod traced;
+#[cfg(feature = "tracing")]
+pub use traced::*;
+
+pub use crate::operators::{Dematerialize, Map, Materialize, Scan, SubscribeOn};
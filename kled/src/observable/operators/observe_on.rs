@@ -1,7 +1,14 @@
 use crate::{core, Never};
-use crate::observable::operators::{Materialize, Dematerialize};
-use crate::observer::ScheduledObserver;
 use crate::observable::Signal;
+use crate::observer::ScheduledObserver;
+use async_std::channel::{unbounded, Receiver, Sender};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
 
 #[operator(
     type = "observable",
@@ -20,14 +27,160 @@ where
     scheduler: Scheduler,
 }
 
-pub type ObserveOn<Upstream, Subscription, Item, Error, Scheduler> =
-    Dematerialize<
-        ObserveOnRaw<
-            Materialize<Upstream, Subscription, Item, Error>,
-            Subscription, Item, Error, Scheduler
-        >,
-        Subscription, Item, Error
-    >;
+#[operator(type = "observable")]
+pub struct ObserveOn<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+    delay_error: bool,
+}
+
+struct ObserveOnObserver<Cancellable, Observer, Item, Error, Scheduler> {
+    data: Arc<Data<Cancellable, Observer, Item, Error, Scheduler>>,
+}
+
+struct Data<Cancellable, Observer, Item, Error, Scheduler> {
+    observer: Mutex<Option<Observer>>,
+    scheduler: Scheduler,
+    delay_error: bool,
+    done: AtomicBool,
+    wip: AtomicUsize,
+    queue: (Sender<Item>, Receiver<Item>),
+    terminal: parking_lot::Mutex<Option<Result<(), Error>>>,
+    phantom: PhantomData<Cancellable>,
+}
+
+impl<Cancellable, Observer, Item, Error, Scheduler>
+    ObserveOnObserver<Cancellable, Observer, Item, Error, Scheduler>
+{
+    fn new(observer: Observer, scheduler: Scheduler, delay_error: bool) -> Self {
+        Self {
+            data: Arc::new(Data {
+                observer: Mutex::new(Some(observer)),
+                scheduler,
+                delay_error,
+                done: AtomicBool::new(false),
+                wip: AtomicUsize::default(),
+                queue: unbounded(),
+                terminal: parking_lot::Mutex::new(None),
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<Cancellable, Observer, Item, Error, Scheduler>
+    Data<Cancellable, Observer, Item, Error, Scheduler>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn drain(&self) {
+        let mut missed = 1;
+        loop {
+            let mut observer = self.observer.lock().await;
+            while let Some(current) = observer.as_mut() {
+                // done must be loaded before polling the queue, as otherwise items emitted
+                // right before the terminal signal could be missed.
+                let done = self.done.load(Ordering::SeqCst);
+                let failed = matches!(*self.terminal.lock(), Some(Err(_)));
+                if done && failed && !self.delay_error {
+                    while self.queue.1.try_recv().is_ok() {}
+                }
+                if let Ok(item) = self.queue.1.try_recv() {
+                    current.on_next(item).await;
+                    continue;
+                }
+                if done {
+                    let terminal = self.terminal.lock().take();
+                    match terminal {
+                        Some(Ok(())) => current.on_completed().await,
+                        Some(Err(error)) => current.on_error(error).await,
+                        None => {}
+                    }
+                    *observer = None;
+                }
+                break;
+            }
+            drop(observer);
+            missed = self.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+            if missed == 0 {
+                break;
+            }
+        }
+    }
+}
+
+// Schedules a drain on the scheduler unless a drain is already in progress, in which case only
+// wip is increased, which causes the active drain to loop once more.
+fn schedule<Cancellable, Observer, Item, Error, Scheduler>(
+    data: &Arc<Data<Cancellable, Observer, Item, Error, Scheduler>>,
+) where
+    Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    if data.wip.fetch_add(1, Ordering::SeqCst) == 0 {
+        let cloned = data.clone();
+        data.scheduler.schedule(async move { cloned.drain().await });
+    }
+}
+
+fn terminate<Cancellable, Observer, Item, Error, Scheduler>(
+    data: &Arc<Data<Cancellable, Observer, Item, Error, Scheduler>>,
+    terminal: Result<(), Error>,
+) where
+    Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    *data.terminal.lock() = Some(terminal);
+    data.done.store(true, Ordering::SeqCst);
+    schedule(data);
+}
+
+#[async_trait]
+impl<Cancellable, Observer, Item, Error, Scheduler> core::Observer<Cancellable, Item, Error>
+    for ObserveOnObserver<Cancellable, Observer, Item, Error, Scheduler>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        if let Some(observer) = self.data.observer.lock().await.as_mut() {
+            observer.on_subscribe(cancellable).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if !self.data.done.load(Ordering::SeqCst) && self.data.queue.0.try_send(item).is_ok() {
+            schedule(&self.data);
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if !self.data.done.load(Ordering::SeqCst) {
+            terminate(&self.data, Err(error));
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if !self.data.done.load(Ordering::SeqCst) {
+            terminate(&self.data, Ok(()));
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -42,7 +195,7 @@ mod tests {
         let test_observer = TestObserver::default();
         vec![0, 1, 2, 3]
             .into_observable()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), false)
             .subscribe(test_observer.clone()).await;
         scheduler.join();
         assert_eq!(test_observer.status().await, ObserverStatus::Completed);
@@ -55,7 +208,7 @@ mod tests {
         let test_observer = TestObserver::default();
         vec![0, 1, 2, 3]
             .into_observable()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), false)
             .subscribe(test_observer.clone()).await;
         scheduler.join();
         assert_eq!(test_observer.status().await, ObserverStatus::Completed);
@@ -69,11 +222,43 @@ mod tests {
         let test_observable = TestObservable::default().annotate_item_type(());
         test_observable
             .clone()
-            .observe_on(scheduler.clone())
+            .observe_on(scheduler.clone(), false)
             .subscribe(test_observer.clone()).await;
         test_observable.emit_error(()).await;
         scheduler.join();
         assert_eq!(test_observer.status().await, ObserverStatus::Error);
         assert_eq!(test_observer.error().await, Some(()));
     }
+
+    #[async_std::test]
+    async fn observe_on_delay_error_thread_pool() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        test_observable
+            .clone()
+            .observe_on(scheduler.clone(), true)
+            .subscribe(test_observer.clone()).await;
+        test_observable.emit_all(0..100).await;
+        test_observable.emit_error(()).await;
+        scheduler.join();
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, (0..100).collect::<Vec<_>>());
+    }
+
+    #[async_std::test]
+    async fn observe_on_delay_error_new_thread() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let test_observer = TestObserver::default();
+        let test_observable = TestObservable::default();
+        test_observable
+            .clone()
+            .observe_on(scheduler.clone(), true)
+            .subscribe(test_observer.clone()).await;
+        test_observable.emit_all(0..100).await;
+        test_observable.emit_error(()).await;
+        scheduler.join();
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(test_observer.items().await, (0..100).collect::<Vec<_>>());
+    }
 }
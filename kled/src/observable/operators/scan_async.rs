@@ -0,0 +1,76 @@
+use crate::core;
+use async_trait::async_trait;
+use futures::prelude::*;
+use std::marker::PhantomData;
+
+#[operator(type = "observable", item = "ItemOut")]
+pub struct ScanAsync<ItemOut, Fut, BinaryOp>
+where
+    ItemOut: Clone,
+    Fut: Future<Output = ItemOut> + Send,
+    BinaryOp: FnMut(ItemOut, Item) -> Fut + Send,
+{
+    initial_value: ItemOut,
+    binary_op: BinaryOp,
+}
+
+struct ScanAsyncObserver<Observer, ItemOut, Fut, BinaryOp> {
+    observer: Observer,
+    previous_value: ItemOut,
+    binary_op: BinaryOp,
+    phantom: PhantomData<Fut>,
+}
+
+impl<Observer, ItemOut, Fut, BinaryOp> ScanAsyncObserver<Observer, ItemOut, Fut, BinaryOp> {
+    fn new(observer: Observer, initial_value: ItemOut, binary_op: BinaryOp) -> Self {
+        Self {
+            observer,
+            previous_value: initial_value,
+            binary_op,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, ItemIn, Observer, ItemOut, Error, Fut, BinaryOp>
+    core::Observer<Cancellable, ItemIn, Error>
+    for ScanAsyncObserver<Observer, ItemOut, Fut, BinaryOp>
+where
+    Observer: core::Observer<Cancellable, ItemOut, Error> + Send,
+    Cancellable: Send + 'static,
+    ItemIn: Send + 'static,
+    ItemOut: Clone + Send + 'static,
+    Error: Send + 'static,
+    Fut: Future<Output = ItemOut> + Send,
+    BinaryOp: FnMut(ItemOut, ItemIn) -> Fut + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+        self.observer.on_next(self.previous_value.clone()).await;
+    }
+
+    async fn on_next(&mut self, item: ItemIn) {
+        self.previous_value = (self.binary_op)(self.previous_value.clone(), item).await;
+        self.observer.on_next(self.previous_value.clone()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn scan_async() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .scan_async(0, |a, b| async move { a + b })
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 0, 1, 3, 6]);
+    }
+}
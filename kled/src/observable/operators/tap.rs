@@ -0,0 +1,89 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(type = "observable")]
+pub struct Tap<NextFn, ErrorFn, CompletedFn>
+where
+    NextFn: FnMut(&Item),
+    ErrorFn: FnMut(&Error),
+    CompletedFn: FnMut(),
+{
+    next_fn: NextFn,
+    error_fn: ErrorFn,
+    completed_fn: CompletedFn,
+}
+
+#[derive(new)]
+struct TapObserver<Observer, NextFn, ErrorFn, CompletedFn> {
+    observer: Observer,
+    next_fn: NextFn,
+    error_fn: ErrorFn,
+    completed_fn: CompletedFn,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, NextFn, ErrorFn, CompletedFn>
+    core::Observer<Cancellable, Item, Error>
+    for TapObserver<Observer, NextFn, ErrorFn, CompletedFn>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    NextFn: FnMut(&Item) + Send,
+    ErrorFn: FnMut(&Error) + Send,
+    CompletedFn: FnMut() + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        (self.next_fn)(&item);
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        (self.error_fn)(&error);
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        (self.completed_fn)();
+        self.observer.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn tap() {
+        let test_observer = TestObserver::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        let count2 = count.clone();
+        let completed = Arc::new(AtomicBool::new(false));
+        let completed2 = completed.clone();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .tap(
+                move |_| {
+                    count2.fetch_add(1, Ordering::Relaxed);
+                },
+                |_| {},
+                move || completed2.store(true, Ordering::Relaxed),
+            )
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(count.load(Ordering::Relaxed), 4);
+        assert!(completed.load(Ordering::Relaxed));
+    }
+}
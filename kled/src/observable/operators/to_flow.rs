@@ -0,0 +1,369 @@
+use crate::core;
+use crate::core::Flow as _;
+use crate::flow;
+use crate::flow::operators::*;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(new)]
+pub struct ToFlow<Observable, Cancellable, Item, Error> {
+    upstream: Observable,
+    backpressure_strategy: flow::BackpressureStrategy,
+    phantom: PhantomData<(Cancellable, Item, Error)>,
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error>
+    core::Flow<ToFlowSubscription<Cancellable, Item, Error>, Item, Error>
+    for ToFlow<Observable, Cancellable, Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<ToFlowSubscription<Cancellable, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let upstream = ObservableFlow::new(self.upstream);
+        use flow::BackpressureStrategy::*;
+        match self.backpressure_strategy {
+            Buffer(buffer_strategy, capacity) => {
+                let config = flow::BufferConfig::new(buffer_strategy).capacity(capacity);
+                OnBackpressureBuffer::new(upstream, config)
+                    .subscribe(ToFlowSubscriber::new(subscriber, ToFlowSubscription::Buffer))
+                    .await
+            }
+            Drop => {
+                OnBackpressureDrop::new(upstream)
+                    .subscribe(ToFlowSubscriber::new(subscriber, ToFlowSubscription::Drop))
+                    .await
+            }
+            Error => {
+                OnBackpressureError::new(upstream)
+                    .subscribe(ToFlowSubscriber::new(subscriber, ToFlowSubscription::Error))
+                    .await
+            }
+            Latest => {
+                OnBackpressureLatest::new(upstream)
+                    .subscribe(ToFlowSubscriber::new(subscriber, ToFlowSubscription::Latest))
+                    .await
+            }
+            Missing => {
+                upstream
+                    .subscribe(ToFlowSubscriber::new(subscriber, ToFlowSubscription::Missing))
+                    .await
+            }
+        }
+    }
+}
+
+// Emits all items of the observable regardless of the demand of its subscriber. The
+// backpressure strategy is applied by the on_backpressure operators on top of it.
+#[derive(new)]
+struct ObservableFlow<Observable, Cancellable, Item, Error> {
+    upstream: Observable,
+    phantom: PhantomData<(Cancellable, Item, Error)>,
+}
+
+#[async_trait]
+impl<Observable, Cancellable, Item, Error>
+    core::Flow<CancellableSubscription<Cancellable>, Item, Error>
+    for ObservableFlow<Observable, Cancellable, Item, Error>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<CancellableSubscription<Cancellable>, Item, Error>
+            + Send
+            + 'static,
+    {
+        self.upstream
+            .subscribe(ObservableFlowObserver::new(subscriber))
+            .await
+    }
+}
+
+#[derive(new)]
+struct ObservableFlowObserver<Subscriber> {
+    subscriber: Subscriber,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Subscriber> core::Observer<Cancellable, Item, Error>
+    for ObservableFlowObserver<Subscriber>
+where
+    Subscriber: core::Subscriber<CancellableSubscription<Cancellable>, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.subscriber
+            .on_subscribe(CancellableSubscription::new(cancellable))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.subscriber.on_error(flow::Error::Upstream(error)).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+// Wraps the subscription of the selected on_backpressure operator, so that all strategies
+// share the subscription type of ToFlow.
+#[derive(new)]
+struct ToFlowSubscriber<Subscriber, Subscription, Cancellable, Item, Error> {
+    subscriber: Subscriber,
+    wrap_fn: fn(Subscription) -> ToFlowSubscription<Cancellable, Item, Error>,
+}
+
+#[async_trait]
+impl<Subscriber, Subscription, Cancellable, Item, Error>
+    core::Subscriber<Subscription, Item, Error>
+    for ToFlowSubscriber<Subscriber, Subscription, Cancellable, Item, Error>
+where
+    Subscriber: core::Subscriber<ToFlowSubscription<Cancellable, Item, Error>, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Cancellable: Send,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber
+            .on_subscribe((self.wrap_fn)(subscription))
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+/// The [`Subscription`] of a [`Flow`] converted from an [`Observable`]. The observable only
+/// supports cancellation, requests are ignored.
+///
+/// [`Subscription`]: ../../core/trait.Subscription.html
+/// [`Flow`]: ../../core/trait.Flow.html
+/// [`Observable`]: ../../core/trait.Observable.html
+pub struct CancellableSubscription<Cancellable> {
+    cancellable: Cancellable,
+    cancelled: AtomicBool,
+}
+
+impl<Cancellable> CancellableSubscription<Cancellable> {
+    fn new(cancellable: Cancellable) -> Self {
+        Self {
+            cancellable,
+            cancelled: AtomicBool::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable> core::Subscription for CancellableSubscription<Cancellable>
+where
+    Cancellable: core::Cancellable + Send + Sync,
+{
+    async fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.cancellable.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn request(&self, _: usize) {}
+
+    fn try_cancel_now(&self) -> bool {
+        if !self.cancellable.try_cancel_now() {
+            return false;
+        }
+        self.cancelled.store(true, Ordering::Relaxed);
+        true
+    }
+
+    fn try_request_now(&self, _: usize) -> bool {
+        true
+    }
+}
+
+/// The [`Subscription`] returned by [`Observable::to_flow`], one variant per
+/// [`BackpressureStrategy`].
+///
+/// [`Subscription`]: ../../core/trait.Subscription.html
+/// [`Observable::to_flow`]: ../../core/trait.Observable.html#method.to_flow
+/// [`BackpressureStrategy`]: ../../flow/enum.BackpressureStrategy.html
+pub enum ToFlowSubscription<Cancellable, Item, Error> {
+    Buffer(OnBackpressureBufferSubscription<CancellableSubscription<Cancellable>, Item, Error>),
+    Drop(OnBackpressureDropSubscription<CancellableSubscription<Cancellable>>),
+    Error(OnBackpressureErrorSubscription<CancellableSubscription<Cancellable>>),
+    Latest(OnBackpressureLatestSubscription<CancellableSubscription<Cancellable>, Item, Error>),
+    Missing(CancellableSubscription<Cancellable>),
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error> core::Subscription for ToFlowSubscription<Cancellable, Item, Error>
+where
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        use ToFlowSubscription::*;
+        match self {
+            Buffer(subscription) => subscription.cancel().await,
+            Drop(subscription) => subscription.cancel().await,
+            Error(subscription) => subscription.cancel().await,
+            Latest(subscription) => subscription.cancel().await,
+            Missing(subscription) => subscription.cancel().await,
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        use ToFlowSubscription::*;
+        match self {
+            Buffer(subscription) => subscription.is_cancelled().await,
+            Drop(subscription) => subscription.is_cancelled().await,
+            Error(subscription) => subscription.is_cancelled().await,
+            Latest(subscription) => subscription.is_cancelled().await,
+            Missing(subscription) => subscription.is_cancelled().await,
+        }
+    }
+
+    async fn request(&self, count: usize) {
+        use ToFlowSubscription::*;
+        match self {
+            Buffer(subscription) => subscription.request(count).await,
+            Drop(subscription) => subscription.request(count).await,
+            Error(subscription) => subscription.request(count).await,
+            Latest(subscription) => subscription.request(count).await,
+            Missing(subscription) => subscription.request(count).await,
+        }
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        use ToFlowSubscription::*;
+        match self {
+            Buffer(subscription) => subscription.try_cancel_now(),
+            Drop(subscription) => subscription.try_cancel_now(),
+            Error(subscription) => subscription.try_cancel_now(),
+            Latest(subscription) => subscription.try_cancel_now(),
+            Missing(subscription) => subscription.try_cancel_now(),
+        }
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        use ToFlowSubscription::*;
+        match self {
+            Buffer(subscription) => subscription.try_request_now(count),
+            Drop(subscription) => subscription.try_request_now(count),
+            Error(subscription) => subscription.try_request_now(count),
+            Latest(subscription) => subscription.try_request_now(count),
+            Missing(subscription) => subscription.try_request_now(count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn missing_strategy() {
+        let test_subscriber = TestSubscriber::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .to_flow(flow::BackpressureStrategy::Missing)
+            .subscribe(test_subscriber.clone()).await;
+        test_observable.emit_all(vec![0, 1, 2]).await;
+        test_observable.emit_on_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn buffer_strategy() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .to_flow(flow::BackpressureStrategy::Buffer(flow::BufferStrategy::Error, 4))
+            .subscribe(test_subscriber.clone()).await;
+        test_observable.emit_all(vec![0, 1, 2]).await;
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        test_subscriber.request_direct(2).await;
+        test_observable.emit_on_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+    }
+
+    #[async_std::test]
+    async fn drop_strategy() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_observable = TestObservable::default().annotate_error_type(());
+        test_observable
+            .clone()
+            .to_flow(flow::BackpressureStrategy::Drop)
+            .subscribe(test_subscriber.clone()).await;
+        test_observable.emit_all(vec![0, 1, 2]).await;
+        test_observable.emit_on_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn upstream_error() {
+        let test_subscriber = TestSubscriber::new(1);
+        let test_observable = TestObservable::default().annotate_item_type(0);
+        test_observable
+            .clone()
+            .to_flow(flow::BackpressureStrategy::Missing)
+            .subscribe(test_subscriber.clone()).await;
+        test_observable.emit_error(42).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::Upstream(42)));
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let mut test_subscriber = TestSubscriber::new(1);
+        let test_observable = TestObservable::default()
+            .annotate_item_type(0)
+            .annotate_error_type(());
+        test_observable
+            .clone()
+            .to_flow(flow::BackpressureStrategy::Latest)
+            .subscribe(test_subscriber.clone()).await;
+        test_subscriber.cancel().await;
+        assert!(test_observable.is_cancelled().await);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::core;
+use async_trait::async_trait;
+use tracing::{Instrument, Span};
+
+#[operator(type = "observable", subscription = "TracedCancellable<Cancellable>")]
+pub struct Traced {
+    name: &'static str,
+}
+
+struct TracedObserver<Observer> {
+    observer: Observer,
+    name: &'static str,
+    span: Span,
+}
+
+impl<Observer> TracedObserver<Observer> {
+    fn new(observer: Observer, name: &'static str) -> Self {
+        Self {
+            observer,
+            name,
+            span: Span::none(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer> core::Observer<Cancellable, Item, Error>
+    for TracedObserver<Observer>
+where
+    Observer: core::Observer<TracedCancellable<Cancellable>, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.span = tracing::debug_span!("observable", name = self.name);
+        tracing::debug!(parent: &self.span, "on_subscribe");
+        let cancellable = TracedCancellable::new(cancellable, self.span.clone());
+        self.observer
+            .on_subscribe(cancellable)
+            .instrument(self.span.clone())
+            .await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        tracing::trace!(parent: &self.span, "on_next");
+        self.observer
+            .on_next(item)
+            .instrument(self.span.clone())
+            .await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        tracing::debug!(parent: &self.span, "on_error");
+        self.observer
+            .on_error(error)
+            .instrument(self.span.clone())
+            .await;
+    }
+
+    async fn on_completed(&mut self) {
+        tracing::debug!(parent: &self.span, "on_completed");
+        self.observer
+            .on_completed()
+            .instrument(self.span.clone())
+            .await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct TracedCancellable<Upstream> {
+    upstream: Upstream,
+    span: Span,
+}
+
+#[async_trait]
+impl<Upstream> core::Cancellable for TracedCancellable<Upstream>
+where
+    Upstream: core::Cancellable + Send + Sync,
+{
+    async fn cancel(&self) {
+        tracing::debug!(parent: &self.span, "cancel");
+        self.upstream.cancel().await;
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        let cancelled = self.upstream.try_cancel_now();
+        if cancelled {
+            tracing::debug!(parent: &self.span, "cancel");
+        }
+        cancelled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TracedCancellable;
+    use crate::cancellable::*;
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::tracing_recorder::*;
+
+    #[async_std::test]
+    async fn traced() {
+        let recorder = test_recorder();
+        let test_observer = TestObserver::default();
+        vec![0, 1]
+            .into_observable()
+            .traced("traced")
+            .subscribe(test_observer.clone())
+            .await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1]);
+        assert_eq!(
+            recorder.events("observable", "traced"),
+            vec![
+                RecordedEvent::new("on_subscribe", None),
+                RecordedEvent::new("on_next", None),
+                RecordedEvent::new("on_next", None),
+                RecordedEvent::new("on_completed", None),
+            ]
+        );
+    }
+
+    #[async_std::test]
+    async fn forwards_cancel() {
+        let recorder = test_recorder();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        test_observable
+            .clone()
+            .traced("forwards_cancel")
+            .subscribe(test_observer.clone())
+            .await;
+        test_observer.cancel().await;
+
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(
+            recorder.events("observable", "forwards_cancel"),
+            vec![
+                RecordedEvent::new("on_subscribe", None),
+                RecordedEvent::new("cancel", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn forwards_try_cancel_now() {
+        let recorder = test_recorder();
+        let stub = ArcCancellableStub::default();
+        let span = tracing::debug_span!("observable", name = "forwards_try_cancel_now");
+        let cancellable = TracedCancellable::new(stub.cancellable(), span);
+        assert!(cancellable.try_cancel_now());
+
+        assert!(stub.is_cancelled());
+        assert_eq!(
+            recorder.events("observable", "forwards_try_cancel_now"),
+            vec![RecordedEvent::new("cancel", None)]
+        );
+    }
+}
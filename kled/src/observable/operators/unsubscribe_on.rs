@@ -0,0 +1,105 @@
+use crate::core;
+use async_trait::async_trait;
+
+#[operator(
+    type = "observable",
+    subscription = "UnsubscribeOnCancellable<Cancellable, Scheduler>"
+)]
+pub struct UnsubscribeOn<Scheduler>
+where
+    Scheduler: core::Scheduler,
+{
+    scheduler: Scheduler,
+}
+
+#[derive(new)]
+struct UnsubscribeOnObserver<Observer, Scheduler> {
+    observer: Observer,
+    scheduler: Scheduler,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, Scheduler> core::Observer<Cancellable, Item, Error>
+    for UnsubscribeOnObserver<Observer, Scheduler>
+where
+    Observer: core::Observer<UnsubscribeOnCancellable<Cancellable, Scheduler>, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancellable = UnsubscribeOnCancellable::new(cancellable, self.scheduler.clone());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[derive(new, Clone)]
+pub struct UnsubscribeOnCancellable<Upstream, Scheduler> {
+    upstream: Upstream,
+    scheduler: Scheduler,
+}
+
+#[async_trait]
+impl<Upstream, Scheduler> core::Cancellable for UnsubscribeOnCancellable<Upstream, Scheduler>
+where
+    Upstream: core::Cancellable + Send + Sync + 'static,
+    Scheduler: core::Scheduler,
+{
+    async fn cancel(&self) {
+        core::Cancellable::try_cancel_now(self);
+    }
+
+    // Scheduling the cancellation never needs to await.
+    fn try_cancel_now(&self) -> bool {
+        let upstream = self.upstream.clone();
+        self.scheduler
+            .schedule(async move { upstream.cancel().await });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn unsubscribe_on() {
+        let scheduler = scheduler::NewThreadScheduler::default();
+        let cancel_thread = Arc::new(Mutex::new(None));
+        let cancel_thread_clone = cancel_thread.clone();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        test_observable
+            .clone()
+            .do_on_cancel(move || {
+                *cancel_thread_clone.lock().unwrap() = Some(std::thread::current().id())
+            })
+            .unsubscribe_on(scheduler.clone())
+            .subscribe(test_observer.clone()).await;
+        test_observer.cancel().await;
+        scheduler.join();
+        assert!(test_observable.is_cancelled().await);
+        let cancel_thread = cancel_thread.lock().unwrap().unwrap();
+        assert_ne!(cancel_thread, std::thread::current().id());
+    }
+}
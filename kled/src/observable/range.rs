@@ -0,0 +1,26 @@
+use crate::core::IntoObservable;
+use crate::observable::IntoIterObservable;
+use std::ops::Range;
+
+/// Returns an [`Observable`] that emits `count` sequential numbers starting at `start` and
+/// completes. The numbers stop before `usize::MAX` if `start + count` would overflow.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn range(start: usize, count: usize) -> IntoIterObservable<Range<usize>> {
+    (start..start.saturating_add(count)).into_observable()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+
+    #[async_std::test]
+    async fn range() {
+        let test_observer = TestObserver::default();
+        observable::range(3, 4)
+            .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![3, 4, 5, 6]);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::core::IntoObservable;
+use crate::observable::IntoIterObservable;
+use std::iter::{self, Repeat};
+
+/// Returns an [`Observable`] that emits clones of `item` until it is cancelled.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+pub fn repeat<Item>(item: Item) -> IntoIterObservable<Repeat<Item>>
+where
+    Item: Clone + Send + 'static,
+{
+    iter::repeat(item).into_observable()
+}
@@ -0,0 +1,276 @@
+use crate::core;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+#[doc(hidden)]
+pub struct ObservableUsing<ResourceFactory, SourceFactory, DisposeFn> {
+    resource_factory: ResourceFactory,
+    source_factory: SourceFactory,
+    dispose_fn: DisposeFn,
+}
+
+#[async_trait]
+impl<ResourceFactory, SourceFactory, DisposeFn, Resource, Observable, Cancellable, Item, Error>
+    core::Observable<UsingCancellable<Cancellable, Resource, DisposeFn>, Item, Error>
+    for ObservableUsing<ResourceFactory, SourceFactory, DisposeFn>
+where
+    ResourceFactory: FnOnce() -> Resource + Send,
+    SourceFactory: FnOnce(&Resource) -> Observable + Send,
+    DisposeFn: FnOnce(Resource) + Send + 'static,
+    Resource: Send + 'static,
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<UsingCancellable<Cancellable, Resource, DisposeFn>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let resource = (self.resource_factory)();
+        let source = (self.source_factory)(&resource);
+        let disposer = Arc::new(Disposer::new(resource, self.dispose_fn));
+        source.subscribe(UsingObserver::new(observer, disposer)).await;
+    }
+}
+
+/// Returns an [`Observable`] that creates a resource with `resource_factory` for each
+/// subscribing [`Observer`] and subscribes it to the `Observable` returned by
+/// `source_factory`. The resource is passed to `dispose_fn` exactly once, as soon as the
+/// `Observable` completes, fails or is cancelled, whichever happens first.
+///
+/// [`Observable`]: ../core/trait.Observable.html
+/// [`Observer`]: ../core/trait.Observer.html
+pub fn using<
+    ResourceFactory,
+    SourceFactory,
+    DisposeFn,
+    Resource,
+    Observable,
+    Cancellable,
+    Item,
+    Error,
+>(
+    resource_factory: ResourceFactory,
+    source_factory: SourceFactory,
+    dispose_fn: DisposeFn,
+) -> ObservableUsing<ResourceFactory, SourceFactory, DisposeFn>
+where
+    ResourceFactory: FnOnce() -> Resource + Send,
+    SourceFactory: FnOnce(&Resource) -> Observable + Send,
+    DisposeFn: FnOnce(Resource) + Send + 'static,
+    Resource: Send + 'static,
+    Observable: core::Observable<Cancellable, Item, Error> + Send,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    ObservableUsing::new(resource_factory, source_factory, dispose_fn)
+}
+
+// Holds the resource until it is disposed. The resource and dispose_fn are taken out under the
+// lock, which guarantees that dispose_fn is called at most once even if termination and
+// cancellation race.
+struct Disposer<Resource, DisposeFn> {
+    inner: parking_lot::Mutex<Option<(Resource, DisposeFn)>>,
+}
+
+impl<Resource, DisposeFn> Disposer<Resource, DisposeFn>
+where
+    DisposeFn: FnOnce(Resource),
+{
+    fn new(resource: Resource, dispose_fn: DisposeFn) -> Self {
+        Self {
+            inner: parking_lot::Mutex::new(Some((resource, dispose_fn))),
+        }
+    }
+
+    fn dispose(&self) {
+        let inner = self.inner.lock().take();
+        if let Some((resource, dispose_fn)) = inner {
+            dispose_fn(resource);
+        }
+    }
+}
+
+#[derive(new)]
+struct UsingObserver<Observer, Resource, DisposeFn> {
+    observer: Observer,
+    disposer: Arc<Disposer<Resource, DisposeFn>>,
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer, Resource, DisposeFn>
+    core::Observer<Cancellable, Item, Error> for UsingObserver<Observer, Resource, DisposeFn>
+where
+    Observer: core::Observer<UsingCancellable<Cancellable, Resource, DisposeFn>, Item, Error>
+        + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Resource: Send,
+    DisposeFn: FnOnce(Resource) + Send,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        let cancellable = UsingCancellable::new(cancellable, self.disposer.clone());
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.disposer.dispose();
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.disposer.dispose();
+        self.observer.on_completed().await;
+    }
+}
+
+/// The [`Cancellable`] of [`observable::using`], which disposes the resource after
+/// cancelling the source.
+///
+/// [`Cancellable`]: ../core/trait.Cancellable.html
+/// [`observable::using`]: fn.using.html
+pub struct UsingCancellable<Upstream, Resource, DisposeFn> {
+    upstream: Upstream,
+    disposer: Arc<Disposer<Resource, DisposeFn>>,
+}
+
+impl<Upstream, Resource, DisposeFn> UsingCancellable<Upstream, Resource, DisposeFn> {
+    fn new(upstream: Upstream, disposer: Arc<Disposer<Resource, DisposeFn>>) -> Self {
+        Self { upstream, disposer }
+    }
+}
+
+impl<Upstream, Resource, DisposeFn> Clone for UsingCancellable<Upstream, Resource, DisposeFn>
+where
+    Upstream: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upstream: self.upstream.clone(),
+            disposer: self.disposer.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Upstream, Resource, DisposeFn> core::Cancellable
+    for UsingCancellable<Upstream, Resource, DisposeFn>
+where
+    Upstream: core::Cancellable + Send + Sync,
+    Resource: Send,
+    DisposeFn: FnOnce(Resource) + Send,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+        self.disposer.dispose();
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        if !self.upstream.try_cancel_now() {
+            return false;
+        }
+        self.disposer.dispose();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observable::*;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::scheduler;
+    #[chronobreak]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[chronobreak]
+    use std::sync::Arc;
+
+    #[async_std::test]
+    async fn using_completed() {
+        let test_observer = TestObserver::default();
+        let disposed = Arc::new(AtomicUsize::new(0));
+        observable::using(
+            || disposed.clone(),
+            |_| vec![0, 1, 2].into_observable(),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2]);
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn using_error() {
+        let test_observer = TestObserver::default();
+        let disposed = Arc::new(AtomicUsize::new(0));
+        observable::using(
+            || disposed.clone(),
+            |_| observable::error::<(), _>(()),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_observer.clone()).await;
+        assert_eq!(test_observer.status().await, ObserverStatus::Error);
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn using_cancel() {
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let disposed = Arc::new(AtomicUsize::new(0));
+        observable::using(
+            || disposed.clone(),
+            |_| test_observable.clone(),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_observer.clone()).await;
+        assert_eq!(disposed.load(Ordering::SeqCst), 0);
+        test_observer.cancel().await;
+        assert!(test_observable.is_cancelled().await);
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+
+    #[async_std::test]
+    async fn using_cancel_races_completion() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let mut test_observer = TestObserver::default();
+        let test_observable = TestObservable::default().annotate_error_type(());
+        let disposed = Arc::new(AtomicUsize::new(0));
+        observable::using(
+            || disposed.clone(),
+            |_| test_observable.clone(),
+            |disposed| {
+                disposed.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .subscribe(test_observer.clone()).await;
+        let test_observable_clone = test_observable.clone();
+        scheduler.schedule(async move {
+            test_observable_clone.emit_all(0..100).await;
+            test_observable_clone.emit_on_completed().await;
+        });
+        test_observer.cancel().await;
+        scheduler.join();
+        assert_eq!(disposed.load(Ordering::SeqCst), 1);
+    }
+}
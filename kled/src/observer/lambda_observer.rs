@@ -1,5 +1,6 @@
 use crate::cancellable::*;
 use crate::core;
+use crate::hooks::AssemblyTrace;
 use async_trait::async_trait;
 
 pub struct LambdaObserver<Cancellable, NextFn, ErrorFn, CompletedFn>
@@ -10,6 +11,7 @@ where
     item_consumer: NextFn,
     error_consumer: ErrorFn,
     completed_consumer: CompletedFn,
+    assembly: AssemblyTrace,
 }
 
 impl<Cancellable, NextFn, ErrorFn, CompletedFn>
@@ -27,6 +29,7 @@ where
             item_consumer,
             error_consumer,
             completed_consumer,
+            assembly: AssemblyTrace::take(),
         }
     }
 
@@ -52,21 +55,25 @@ where
     }
 
     async fn on_next(&mut self, item: Item) {
-        (self.item_consumer)(item)
+        let item_consumer = &mut self.item_consumer;
+        self.assembly.scope(|| item_consumer(item))
     }
 
     async fn on_error(&mut self, error: Error) {
-        (self.error_consumer)(error)
+        let error_consumer = &mut self.error_consumer;
+        self.assembly.scope(|| error_consumer(error))
     }
 
     async fn on_completed(&mut self) {
-        (self.completed_consumer)()
+        self.assembly.scope(&mut self.completed_consumer)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::observable::*;
     use crate::prelude::*;
+    use crate::scheduler;
 
     #[async_std::test]
     async fn subscribe_next() {
@@ -79,4 +86,20 @@ mod tests {
             })
             .await;
     }
+
+    #[async_std::test]
+    async fn subscribe_scoped() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_observable = TestObservable::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let guard = test_observable
+            .clone()
+            .subscribe_scoped(|_| {}, scheduler.clone())
+            .await;
+        assert!(!test_observable.is_cancelled().await);
+        drop(guard);
+        scheduler.join();
+        assert!(test_observable.is_cancelled().await);
+    }
 }
@@ -1,5 +1,8 @@
 reexport_all! {
     mod lambda_observer;
+    mod observer_as_raw;
+    mod raw_as_observer;
+    mod safe_observer;
     mod scheduled_observer;
     mod test_observer;
 }
@@ -0,0 +1,84 @@
+use crate::core;
+use futures::ready;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type PendingSignal<Observer> = Pin<Box<dyn Future<Output = Observer> + Send>>;
+
+/// Adapts an [`Observer`] to the [`RawObserver`] trait. The observer is moved into the future
+/// of the signal currently being processed and returned once it completes.
+///
+/// [`Observer`]: ../core/trait.Observer.html
+/// [`RawObserver`]: ../core/trait.RawObserver.html
+pub struct ObserverAsRaw<Observer> {
+    observer: Option<Observer>,
+    pending: Option<PendingSignal<Observer>>,
+}
+
+impl<Observer> ObserverAsRaw<Observer> {
+    pub fn new(observer: Observer) -> Self {
+        Self {
+            observer: Some(observer),
+            pending: None,
+        }
+    }
+
+    fn start<F, Fut>(&mut self, f: F)
+    where
+        F: FnOnce(Observer) -> Fut,
+        Fut: Future<Output = Observer> + Send + 'static,
+    {
+        let observer = self
+            .observer
+            .take()
+            .expect("poll_ready must return Poll::Ready before a signal is started");
+        self.pending = Some(Box::pin(f(observer)));
+    }
+}
+
+impl<Cancellable, Item, Error, Observer> core::RawObserver<Cancellable, Item, Error>
+    for ObserverAsRaw<Observer>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if let Some(pending) = &mut self.pending {
+            let observer = ready!(pending.as_mut().poll(cx));
+            self.pending = None;
+            self.observer = Some(observer);
+        }
+        Poll::Ready(())
+    }
+
+    fn start_subscribe(&mut self, cancellable: Cancellable) {
+        self.start(|mut observer| async move {
+            observer.on_subscribe(cancellable).await;
+            observer
+        });
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.start(|mut observer| async move {
+            observer.on_next(item).await;
+            observer
+        });
+    }
+
+    fn start_error(&mut self, error: Error) {
+        self.start(|mut observer| async move {
+            observer.on_error(error).await;
+            observer
+        });
+    }
+
+    fn start_completed(&mut self) {
+        self.start(|mut observer| async move {
+            observer.on_completed().await;
+            observer
+        });
+    }
+}
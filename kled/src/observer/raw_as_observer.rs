@@ -0,0 +1,52 @@
+use crate::core;
+use async_trait::async_trait;
+use futures::future;
+
+/// Adapts a [`RawObserver`] to the [`Observer`] trait. Each signal is started once the raw
+/// observer is ready and then polled until it has been processed.
+///
+/// [`RawObserver`]: ../core/trait.RawObserver.html
+/// [`Observer`]: ../core/trait.Observer.html
+pub struct RawAsObserver<Observer> {
+    observer: Observer,
+}
+
+impl<Observer> RawAsObserver<Observer> {
+    pub fn new(observer: Observer) -> Self {
+        Self { observer }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer> core::Observer<Cancellable, Item, Error>
+    for RawAsObserver<Observer>
+where
+    Observer: core::RawObserver<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+        self.observer.start_subscribe(cancellable);
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+        self.observer.start_next(item);
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+        self.observer.start_error(error);
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+    }
+
+    async fn on_completed(&mut self) {
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+        self.observer.start_completed();
+        future::poll_fn(|cx| self.observer.poll_ready(cx)).await;
+    }
+}
@@ -0,0 +1,92 @@
+use crate::core;
+use crate::hooks::ProtocolState;
+use async_trait::async_trait;
+
+/// An [`Observer`] which enforces the grammar `on_subscribe on_next* (on_error | on_completed)?`
+/// for the wrapped observer. Signals emitted after `on_error` or `on_completed` are dropped,
+/// and violations are reported to [`hooks::on_protocol_violation`].
+///
+/// The checks are only performed if they were enabled via [`hooks::set_protocol_checks`] when
+/// the `SafeObserver` was created. `subscribe_next` and `subscribe_all` of [`Observable`]
+/// wrap their observers in a `SafeObserver`.
+///
+/// [`Observer`]: ../core/trait.Observer.html
+/// [`Observable`]: ../core/trait.Observable.html
+/// [`hooks::on_protocol_violation`]: ../hooks/fn.on_protocol_violation.html
+/// [`hooks::set_protocol_checks`]: ../hooks/fn.set_protocol_checks.html
+pub struct SafeObserver<Observer> {
+    observer: Observer,
+    state: ProtocolState,
+}
+
+impl<Observer> SafeObserver<Observer> {
+    pub fn new(observer: Observer) -> Self {
+        Self {
+            observer,
+            state: ProtocolState::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Cancellable, Item, Error, Observer> core::Observer<Cancellable, Item, Error>
+    for SafeObserver<Observer>
+where
+    Observer: core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.state.begin_on_next() {
+            self.observer.on_next(item).await;
+            self.state.end_on_next();
+        }
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        if self.state.terminate("on_error") {
+            self.observer.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if self.state.terminate("on_completed") {
+            self.observer.on_completed().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hooks::{self, ProtocolViolation};
+    use crate::prelude::*;
+    use crate::subject::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn drops_signals_after_termination() {
+        let violations = hooks::test_violations();
+        let items = Arc::new(Mutex::new(vec![]));
+        let items_clone = items.clone();
+        let mut subject: PublishSubject<(), i32, ()> = PublishSubject::default();
+        subject
+            .clone()
+            .subscribe_next(move |item| items_clone.lock().unwrap().push(item))
+            .await;
+        subject.on_subscribe(()).await;
+        subject.on_next(0).await;
+        subject.on_completed().await;
+        subject.on_next(1).await;
+
+        assert_eq!(*items.lock().unwrap(), vec![0]);
+        let signal = "on_next";
+        let violation = ProtocolViolation::SignalAfterTermination { signal };
+        assert_eq!(violations.on_current_thread(), vec![violation]);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::observable::Signal;
+use crate::{core, Never};
+use std::task::{Context, Poll};
+
+#[operator(
+    type = "both",
+    upstream_subscription = "Never",
+    upstream_item = "Signal<Subscription, Item, Error>",
+    upstream_error = "Never",
+    raw = true,
+)]
+pub struct Dematerialize {}
+
+#[derive(new)]
+struct DematerializeObserver<Observer> {
+    observer: Observer,
+}
+
+// The upstream error is `Never` for observables and `flow::Error<Never>` for flows.
+impl<Subscription, Observer, Item, Error, UpstreamError>
+    core::RawObserver<Never, Signal<Subscription, Item, Error>, UpstreamError>
+    for DematerializeObserver<Observer>
+where
+    Observer: core::RawObserver<Subscription, Item, Error>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.observer.poll_ready(cx)
+    }
+
+    fn start_subscribe(&mut self, _: Never) {
+        unreachable! {}
+    }
+
+    fn start_next(&mut self, signal: Signal<Subscription, Item, Error>) {
+        match signal {
+            Signal::Subscribe(subscription) => self.observer.start_subscribe(subscription),
+            Signal::Item(item) => self.observer.start_next(item),
+            Signal::Error(err) => self.observer.start_error(err),
+            Signal::Completed => {}
+        }
+    }
+
+    fn start_error(&mut self, _: UpstreamError) {
+        unreachable! {}
+    }
+
+    fn start_completed(&mut self) {
+        self.observer.start_completed();
+    }
+}
+
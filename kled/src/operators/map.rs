@@ -0,0 +1,119 @@
+use crate::core;
+use std::marker::PhantomData;
+use std::task::{Context, Poll};
+
+#[operator(type = "both", item = "ItemOut", raw = true)]
+pub struct Map<ItemOut, UnaryOp>
+where
+    UnaryOp: FnMut(Item) -> ItemOut + Send,
+{
+    unary_op: UnaryOp,
+}
+
+#[derive(new)]
+struct MapObserver<Observer, ItemOut, UnaryOp> {
+    observer: Observer,
+    unary_op: UnaryOp,
+    phantom: PhantomData<ItemOut>,
+}
+
+impl<Subscription, ItemIn, Observer, ItemOut, Error, UnaryOp>
+    core::RawObserver<Subscription, ItemIn, Error> for MapObserver<Observer, ItemOut, UnaryOp>
+where
+    Observer: core::RawObserver<Subscription, ItemOut, Error>,
+    UnaryOp: FnMut(ItemIn) -> ItemOut,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.observer.poll_ready(cx)
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.observer.start_subscribe(subscription);
+    }
+
+    fn start_next(&mut self, item: ItemIn) {
+        self.observer.start_next((self.unary_op)(item));
+    }
+
+    fn start_error(&mut self, error: Error) {
+        self.observer.start_error(error);
+    }
+
+    fn start_completed(&mut self) {
+        self.observer.start_completed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::TestFlow;
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn local_observable() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .map(|a| a + 1)
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![1, 2, 3, 4]);
+    }
+
+    #[async_std::test]
+    async fn local_flow() {
+        let test_subscriber = TestSubscriber::default();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .map(|a| a + 1)
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2, 3, 4]);
+    }
+
+    #[async_std::test]
+    async fn raw_chain_observable() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .map(|a| a + 1)
+            .scan(0, |a, b| a + b)
+            .map(|a| a * 2)
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 2, 6, 12, 20]);
+    }
+
+    #[async_std::test]
+    async fn raw_chain_flow() {
+        let requested = Arc::new(Mutex::new(vec![]));
+        let requested_clone = requested.clone();
+        let test_subscriber = TestSubscriber::new(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .do_on_request(move |count| requested_clone.lock().unwrap().push(count))
+            .map(|a| a + 1)
+            .scan(0, |a, b| a + b)
+            .map(|a| a * 2)
+            .subscribe(test_subscriber.clone()).await;
+        assert_eq!(*requested.lock().unwrap(), vec![2]);
+
+        test_flow.emit(0).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 2]);
+        test_subscriber.request_direct(3).await;
+        assert_eq!(*requested.lock().unwrap(), vec![2, 3]);
+
+        test_flow.emit_all(vec![1, 2, 3]).await;
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 2, 6, 12, 20]);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::observable::Signal;
+use crate::{core, Never};
+use futures::ready;
+use std::marker::PhantomData;
+use std::task::{Context, Poll};
+
+#[operator(
+    type = "both",
+    subscription = "Never",
+    item = "Signal<Subscription, Item, Error>",
+    error = "Never",
+    raw = true,
+)]
+pub struct Materialize {}
+
+struct MaterializeObserver<Observer, DownstreamError> {
+    observer: Observer,
+    // Set once Signal::Completed has been emitted, on_completed follows once the downstream
+    // observer is ready.
+    completed: bool,
+    phantom: PhantomData<DownstreamError>,
+}
+
+impl<Observer, DownstreamError> MaterializeObserver<Observer, DownstreamError> {
+    fn new(observer: Observer) -> Self {
+        Self {
+            observer,
+            completed: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// The downstream error is `Never` for observables and `flow::Error<Never>` for flows.
+impl<Subscription, Observer, Item, Error, DownstreamError>
+    core::RawObserver<Subscription, Item, Error> for MaterializeObserver<Observer, DownstreamError>
+where
+    Observer: core::RawObserver<Never, Signal<Subscription, Item, Error>, DownstreamError>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        ready!(self.observer.poll_ready(cx));
+        if self.completed {
+            self.completed = false;
+            self.observer.start_completed();
+            ready!(self.observer.poll_ready(cx));
+        }
+        Poll::Ready(())
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.observer.start_next(Signal::Subscribe(subscription));
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.observer.start_next(Signal::Item(item));
+    }
+
+    fn start_error(&mut self, error: Error) {
+        self.observer.start_next(Signal::Error(error));
+    }
+
+    fn start_completed(&mut self) {
+        self.observer.start_next(Signal::Completed);
+        self.completed = true;
+    }
+}
@@ -0,0 +1,12 @@
+//! Operators which are defined for both [`Observable`]s and [`Flow`]s.
+//!
+//! [`Observable`]: ../core/trait.Observable.html
+//! [`Flow`]: ../core/trait.Flow.html
+
+reexport_all! {
+    mod dematerialize;
+    mod map;
+    mod materialize;
+    mod scan;
+    mod subscribe_on;
+}
@@ -0,0 +1,100 @@
+use crate::core;
+use futures::ready;
+use std::task::{Context, Poll};
+
+#[operator(type = "both", item = "ItemOut", raw = true)]
+pub struct Scan<ItemOut, BinaryOp>
+where
+    ItemOut: Clone,
+    BinaryOp: FnMut(ItemOut, Item) -> ItemOut,
+{
+    initial_value: ItemOut,
+    binary_op: BinaryOp,
+}
+
+struct ScanObserver<Observer, ItemOut, BinaryOp> {
+    observer: Observer,
+    previous_value: ItemOut,
+    binary_op: BinaryOp,
+    // The initial value, which is emitted once the downstream observer is ready after
+    // on_subscribe.
+    pending: Option<ItemOut>,
+}
+
+impl<Observer, ItemOut, BinaryOp> ScanObserver<Observer, ItemOut, BinaryOp> {
+    fn new(observer: Observer, initial_value: ItemOut, binary_op: BinaryOp) -> Self {
+        Self {
+            observer,
+            previous_value: initial_value,
+            binary_op,
+            pending: None,
+        }
+    }
+}
+
+impl<Subscription, ItemIn, Observer, ItemOut, Error, BinaryOp>
+    core::RawObserver<Subscription, ItemIn, Error> for ScanObserver<Observer, ItemOut, BinaryOp>
+where
+    Observer: core::RawObserver<Subscription, ItemOut, Error>,
+    BinaryOp: FnMut(ItemOut, ItemIn) -> ItemOut,
+    ItemOut: Clone,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            ready!(self.observer.poll_ready(cx));
+            match self.pending.take() {
+                Some(value) => self.observer.start_next(value),
+                None => return Poll::Ready(()),
+            }
+        }
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.observer.start_subscribe(subscription);
+        self.pending = Some(self.previous_value.clone());
+    }
+
+    fn start_next(&mut self, item: ItemIn) {
+        self.previous_value = (self.binary_op)(self.previous_value.clone(), item);
+        self.observer.start_next(self.previous_value.clone());
+    }
+
+    fn start_error(&mut self, error: Error) {
+        self.observer.start_error(error);
+    }
+
+    fn start_completed(&mut self) {
+        self.observer.start_completed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::observer::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn local_observable() {
+        let test_observer = TestObserver::default();
+        vec![0, 1, 2, 3]
+            .into_observable()
+            .scan(0, |a, b| a + b)
+            .subscribe(test_observer.clone()).await;
+
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 0, 1, 3, 6]);
+    }
+
+    #[async_std::test]
+    async fn local_flow() {
+        let test_subscriber = TestSubscriber::default();
+        vec![0, 1, 2, 3]
+            .into_flow()
+            .scan(0, |a, b| a + b)
+            .subscribe(test_subscriber.clone()).await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 0, 1, 3, 6]);
+    }
+}
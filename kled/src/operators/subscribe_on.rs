@@ -2,13 +2,34 @@ use crate::core;
 use async_trait::async_trait;
 use std::marker::PhantomData;
 
+// Not derived via #[operator], as the upstream is subscribed to on the scheduler instead of
+// wrapping the downstream observer or subscriber.
 #[derive(new)]
-pub struct SubscribeOn<Flow, Subscription, Item, Error, Scheduler> {
-    flow: Flow,
+pub struct SubscribeOn<Upstream, Subscription, Item, Error, Scheduler> {
+    upstream: Upstream,
     scheduler: Scheduler,
     phantom: PhantomData<(Subscription, Item, Error)>,
 }
 
+#[async_trait]
+impl<Observable, Cancellable, Item, Error, Scheduler> core::Observable<Cancellable, Item, Error>
+    for SubscribeOn<Observable, Cancellable, Item, Error, Scheduler>
+where
+    Observable: core::Observable<Cancellable, Item, Error> + Send + 'static,
+    Cancellable: core::Cancellable + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler + Send + 'static,
+{
+    async fn subscribe<Observer>(self, observer: Observer)
+    where
+        Observer: core::Observer<Cancellable, Item, Error> + Send + 'static,
+    {
+        let observable = self.upstream;
+        self.scheduler.schedule(observable.subscribe(observer));
+    }
+}
+
 #[async_trait]
 impl<Flow, Subscription, Item, Error, Scheduler> core::Flow<Subscription, Item, Error>
     for SubscribeOn<Flow, Subscription, Item, Error, Scheduler>
@@ -23,33 +44,34 @@ where
     where
         Subscriber: core::Subscriber<Subscription, Item, Error> + Send + 'static,
     {
-        let flow = self.flow;
+        let flow = self.upstream;
         self.scheduler.schedule(flow.subscribe(subscriber));
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::observer::*;
     use crate::prelude::*;
     use crate::scheduler;
     use crate::subscriber::*;
 
     #[async_std::test]
-    async fn subscribe_on() {
+    async fn subscribe_on_observable() {
         let scheduler = scheduler::ThreadPoolScheduler::default();
-        let test_subscriber = TestSubscriber::default();
+        let test_observer = TestObserver::default();
         vec![0, 1, 2, 3]
-            .into_flow()
+            .into_observable()
             .subscribe_on(scheduler.clone())
-            .subscribe(test_subscriber.clone())
+            .subscribe(test_observer.clone())
             .await;
         scheduler.join();
-        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
-        assert_eq!(test_subscriber.items().await, vec![0, 1, 2, 3]);
+        assert_eq!(test_observer.status().await, ObserverStatus::Completed);
+        assert_eq!(test_observer.items().await, vec![0, 1, 2, 3]);
     }
 
     #[async_std::test]
-    async fn subscribe_on_shared() {
+    async fn subscribe_on_flow() {
         let scheduler = scheduler::ThreadPoolScheduler::default();
         let test_subscriber = TestSubscriber::default();
         vec![0, 1, 2, 3]
@@ -0,0 +1,146 @@
+use crate::core;
+use crate::flow;
+use crate::flow::operators::DemandSubscription;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct Filter<ParallelFlow, Subscription, Item, Error, Predicate> {
+    upstream: ParallelFlow,
+    predicate: Predicate,
+    phantom: PhantomData<(Subscription, Item, Error)>,
+}
+
+#[async_trait]
+impl<ParallelFlow, Subscription, Item, Error, Predicate>
+    core::ParallelFlow<DemandSubscription<Subscription>, Item, Error>
+    for Filter<ParallelFlow, Subscription, Item, Error, Predicate>
+where
+    ParallelFlow: core::ParallelFlow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Predicate: Fn(&Item) -> bool + Send + Sync + 'static,
+{
+    fn rails(&self) -> usize {
+        self.upstream.rails()
+    }
+
+    async fn subscribe<Subscriber>(self, subscribers: Vec<Subscriber>)
+    where
+        Subscriber:
+            core::Subscriber<DemandSubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        let predicate = Arc::new(self.predicate);
+        let subscribers = subscribers
+            .into_iter()
+            .map(|subscriber| FilterSubscriber::new(subscriber, predicate.clone()))
+            .collect();
+        self.upstream.subscribe(subscribers).await;
+    }
+}
+
+struct FilterSubscriber<Subscriber, Subscription, Predicate> {
+    subscriber: Subscriber,
+    predicate: Arc<Predicate>,
+    subscription: Option<DemandSubscription<Subscription>>,
+}
+
+impl<Subscriber, Subscription, Predicate> FilterSubscriber<Subscriber, Subscription, Predicate> {
+    fn new(subscriber: Subscriber, predicate: Arc<Predicate>) -> Self {
+        Self {
+            subscriber,
+            predicate,
+            subscription: None,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber, Predicate> core::Subscriber<Subscription, Item, Error>
+    for FilterSubscriber<Subscriber, Subscription, Predicate>
+where
+    Subscriber: core::Subscriber<DemandSubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Predicate: Fn(&Item) -> bool + Send + Sync,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = DemandSubscription::new(subscription);
+        self.subscription = Some(subscription.clone());
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if (self.predicate)(&item) {
+            self.subscriber.on_next(item).await;
+        } else if let Some(subscription) = &self.subscription {
+            // The dropped item still counted towards the downstream's demand.
+            if !core::Subscription::try_request_now(subscription, 1) {
+                core::Subscription::request(subscription, 1).await;
+            }
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn filter() {
+        let rail0 = TestSubscriber::default();
+        let rail1 = TestSubscriber::default();
+        (0..8)
+            .into_flow()
+            .parallel(2)
+            .filter(|a| a % 3 != 0)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        assert_eq!(rail0.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail0.items().await, vec![2, 4]);
+        assert_eq!(rail1.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail1.items().await, vec![1, 5, 7]);
+    }
+
+    #[async_std::test]
+    async fn requests_replacements() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let rail0 = TestSubscriber::new(1);
+        let rail1 = TestSubscriber::new(1);
+        test_flow
+            .clone()
+            .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+            .parallel(2)
+            .filter(|a| a % 2 == 0)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        assert_eq!(*requests.lock().unwrap(), vec![1, 1]);
+        test_flow.emit_all(0..2).await;
+        assert_eq!(rail0.items().await, vec![0]);
+        assert!(rail1.items().await.is_empty());
+        // The replacement for rail 1 is requested together with the next item of rail 0.
+        assert_eq!(*requests.lock().unwrap(), vec![1, 1]);
+        rail0.request_direct(1).await;
+        assert_eq!(*requests.lock().unwrap(), vec![1, 1, 2]);
+    }
+}
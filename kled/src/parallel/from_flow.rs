@@ -0,0 +1,305 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct FromFlow<Flow, Subscription, Item, Error> {
+    flow: Flow,
+    rails: usize,
+    phantom: PhantomData<(Subscription, Item, Error)>,
+}
+
+#[async_trait]
+impl<Flow, Subscription, Item, Error>
+    core::ParallelFlow<RailSubscription<Subscription>, Item, Error>
+    for FromFlow<Flow, Subscription, Item, Error>
+where
+    Flow: core::Flow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+{
+    fn rails(&self) -> usize {
+        self.rails
+    }
+
+    async fn subscribe<Subscriber>(self, subscribers: Vec<Subscriber>)
+    where
+        Subscriber: core::Subscriber<RailSubscription<Subscription>, Item, Error> + Send + 'static,
+    {
+        assert_eq!(subscribers.len(), self.rails);
+        self.flow.subscribe(DispatchSubscriber::new(subscribers)).await;
+    }
+}
+
+struct DispatchSubscriber<Subscriber, Subscription> {
+    subscribers: Vec<Subscriber>,
+    data: Option<Arc<Data<Subscription>>>,
+    // The rail receiving the next item.
+    next: usize,
+}
+
+impl<Subscriber, Subscription> DispatchSubscriber<Subscriber, Subscription> {
+    fn new(subscribers: Vec<Subscriber>) -> Self {
+        Self {
+            subscribers,
+            data: None,
+            next: 0,
+        }
+    }
+
+    fn is_cancelled(&self, rail: usize) -> bool {
+        self.data
+            .as_ref()
+            .map_or(false, |data| data.state.lock().cancelled[rail])
+    }
+}
+
+struct Data<Subscription> {
+    upstream: Subscription,
+    state: parking_lot::Mutex<State>,
+}
+
+// The items are assigned to the rails in strict round-robin order, so that each rail receives
+// every n-th item. Items are only requested from the upstream once the rail they will be
+// assigned to has requested them, where cancelled rails count as having requested unlimited
+// items, which are dropped.
+struct State {
+    // The number of items requested by each rail which have not been requested from the
+    // upstream yet.
+    requested: Vec<usize>,
+    cancelled: Vec<bool>,
+    // The rail to which the next item requested from the upstream will be assigned.
+    next: usize,
+}
+
+impl State {
+    fn new(rails: usize) -> Self {
+        Self {
+            requested: vec![0; rails],
+            cancelled: vec![false; rails],
+            next: 0,
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.iter().all(|cancelled| *cancelled)
+    }
+
+    // Returns the number of items which can be requested from the upstream and reserves them
+    // for the corresponding rails.
+    fn reserve(&mut self) -> usize {
+        if self.is_cancelled() {
+            return 0;
+        }
+        let rails = self.requested.len();
+        let rounds = (0..rails)
+            .filter(|rail| !self.cancelled[*rail])
+            .map(|rail| self.requested[rail])
+            .min()
+            .unwrap_or(0);
+        for rail in 0..rails {
+            if !self.cancelled[rail] {
+                self.requested[rail] -= rounds;
+            }
+        }
+        let mut count = rounds.saturating_mul(rails);
+        // After the full rounds, at least one rail which is not cancelled has no pending
+        // demand, so that the partial round ends within one pass.
+        while self.cancelled[self.next] || self.requested[self.next] > 0 {
+            if !self.cancelled[self.next] {
+                self.requested[self.next] -= 1;
+            }
+            count = count.saturating_add(1);
+            self.next = (self.next + 1) % rails;
+        }
+        count
+    }
+}
+
+impl<Subscription> Data<Subscription>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn request_upstream(&self, count: usize) {
+        if count > 0 && !self.upstream.try_request_now(count) {
+            self.upstream.request(count).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> core::Subscriber<Subscription, Item, Error>
+    for DispatchSubscriber<Subscriber, Subscription>
+where
+    Subscriber: core::Subscriber<RailSubscription<Subscription>, Item, Error> + Send,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Clone + Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let data = Arc::new(Data {
+            upstream: subscription,
+            state: parking_lot::Mutex::new(State::new(self.subscribers.len())),
+        });
+        self.data = Some(data.clone());
+        for (index, subscriber) in self.subscribers.iter_mut().enumerate() {
+            subscriber
+                .on_subscribe(RailSubscription::new(index, data.clone()))
+                .await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        let rail = self.next;
+        self.next = (self.next + 1) % self.subscribers.len();
+        if !self.is_cancelled(rail) {
+            self.subscribers[rail].on_next(item).await;
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        for rail in 0..self.subscribers.len() {
+            if !self.is_cancelled(rail) {
+                self.subscribers[rail].on_error(error.clone()).await;
+            }
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        for rail in 0..self.subscribers.len() {
+            if !self.is_cancelled(rail) {
+                self.subscribers[rail].on_completed().await;
+            }
+        }
+    }
+}
+
+/// The [`Subscription`] of a single rail of a [`ParallelFlow`] created via
+/// [`Flow::parallel`]. Requests are forwarded to the upstream [`Flow`] once all rails
+/// preceding the requesting rail in round-robin order have requested items as well, and the
+/// upstream is cancelled once all rails have been cancelled.
+///
+/// [`Subscription`]: ../core/trait.Subscription.html
+/// [`ParallelFlow`]: ../core/trait.ParallelFlow.html
+/// [`Flow::parallel`]: ../core/trait.Flow.html#method.parallel
+/// [`Flow`]: ../core/trait.Flow.html
+pub struct RailSubscription<Subscription> {
+    index: usize,
+    data: Arc<Data<Subscription>>,
+}
+
+impl<Subscription> RailSubscription<Subscription> {
+    fn new(index: usize, data: Arc<Data<Subscription>>) -> Self {
+        Self { index, data }
+    }
+}
+
+#[async_trait]
+impl<Subscription> core::Subscription for RailSubscription<Subscription>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+{
+    async fn cancel(&self) {
+        let (cancelled, count) = {
+            let mut state = self.data.state.lock();
+            state.cancelled[self.index] = true;
+            // The demand of the other rails is no longer held back by this rail.
+            (state.is_cancelled(), state.reserve())
+        };
+        if cancelled {
+            self.data.upstream.cancel().await;
+        } else {
+            self.data.request_upstream(count).await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.state.lock().cancelled[self.index]
+    }
+
+    async fn request(&self, count: usize) {
+        let count = {
+            let mut state = self.data.state.lock();
+            if state.cancelled[self.index] {
+                return;
+            }
+            state.requested[self.index] = state.requested[self.index].saturating_add(count);
+            state.reserve()
+        };
+        self.data.request_upstream(count).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+    #[chronobreak]
+    use std::sync::{Arc, Mutex};
+
+    #[async_std::test]
+    async fn splits_demand() {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let requests_clone = requests.clone();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let rail0 = TestSubscriber::default();
+        let rail1 = TestSubscriber::default();
+        test_flow
+            .clone()
+            .do_on_request(move |count| requests_clone.lock().unwrap().push(count))
+            .parallel(2)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        rail1.request_direct(2).await;
+        assert!(requests.lock().unwrap().is_empty());
+        rail0.request_direct(1).await;
+        assert_eq!(*requests.lock().unwrap(), vec![2]);
+        test_flow.emit_all(0..2).await;
+        assert_eq!(rail0.items().await, vec![0]);
+        assert_eq!(rail1.items().await, vec![1]);
+        rail0.request_direct(1).await;
+        assert_eq!(*requests.lock().unwrap(), vec![2, 2]);
+    }
+
+    #[async_std::test]
+    async fn cancelled_rails() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let mut rail0 = TestSubscriber::new(10);
+        let mut rail1 = TestSubscriber::default();
+        test_flow
+            .clone()
+            .parallel(2)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        rail1.cancel().await;
+        test_flow.emit_all(0..4).await;
+        assert_eq!(rail0.items().await, vec![0, 2]);
+        assert!(rail1.items().await.is_empty());
+        assert!(!test_flow.is_cancelled().await);
+        rail0.cancel().await;
+        assert!(test_flow.is_cancelled().await);
+    }
+
+    #[async_std::test]
+    async fn error_reaches_all_rails() {
+        let test_flow = TestFlow::default().annotate_item_type(());
+        let rail0 = TestSubscriber::default();
+        let rail1 = TestSubscriber::default();
+        test_flow
+            .clone()
+            .parallel(2)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        test_flow.emit_error(()).await;
+        assert_eq!(rail0.error().await, Some(flow::Error::Upstream(())));
+        assert_eq!(rail1.error().await, Some(flow::Error::Upstream(())));
+    }
+}
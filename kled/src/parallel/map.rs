@@ -0,0 +1,88 @@
+use crate::core;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct Map<ParallelFlow, Subscription, Item, Error, ItemOut, UnaryOp> {
+    upstream: ParallelFlow,
+    unary_op: UnaryOp,
+    phantom: PhantomData<(Subscription, Item, Error, ItemOut)>,
+}
+
+#[async_trait]
+impl<ParallelFlow, Subscription, Item, Error, ItemOut, UnaryOp>
+    core::ParallelFlow<Subscription, ItemOut, Error>
+    for Map<ParallelFlow, Subscription, Item, Error, ItemOut, UnaryOp>
+where
+    ParallelFlow: core::ParallelFlow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    ItemOut: Send + 'static,
+    UnaryOp: Fn(Item) -> ItemOut + Send + Sync + 'static,
+{
+    fn rails(&self) -> usize {
+        self.upstream.rails()
+    }
+
+    async fn subscribe<Subscriber>(self, subscribers: Vec<Subscriber>)
+    where
+        Subscriber: core::Subscriber<Subscription, ItemOut, Error> + Send + 'static,
+    {
+        let unary_op = Arc::new(self.unary_op);
+        let subscribers = subscribers
+            .into_iter()
+            .map(|subscriber| MapSubscriber::new(subscriber, unary_op.clone()))
+            .collect();
+        self.upstream.subscribe(subscribers).await;
+    }
+}
+
+#[derive(new)]
+struct MapSubscriber<Subscriber, UnaryOp> {
+    subscriber: Subscriber,
+    unary_op: Arc<UnaryOp>,
+}
+
+#[subscriber]
+#[async_trait]
+impl<Subscription, ItemIn, Subscriber, ItemOut, Error, UnaryOp>
+    core::Subscriber<Subscription, ItemIn, Error> for MapSubscriber<Subscriber, UnaryOp>
+where
+    Subscriber: core::Subscriber<Subscription, ItemOut, Error> + Send,
+    Subscription: Send + 'static,
+    ItemIn: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+    UnaryOp: Fn(ItemIn) -> ItemOut + Send + Sync,
+{
+    async fn on_next(&mut self, item: ItemIn) {
+        let item = (self.unary_op)(item);
+        self.subscriber.on_next(item).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn map() {
+        let rail0 = TestSubscriber::default();
+        let rail1 = TestSubscriber::default();
+        (0..5)
+            .into_flow()
+            .parallel(2)
+            .map(|a| a + 1)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        assert_eq!(rail0.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail0.items().await, vec![1, 3, 5]);
+        assert_eq!(rail1.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail1.items().await, vec![2, 4]);
+    }
+}
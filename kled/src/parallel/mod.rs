@@ -0,0 +1,8 @@
+reexport_all! {
+    mod filter;
+    mod from_flow;
+    mod map;
+    mod reduce;
+    mod run_on;
+    mod sequential;
+}
@@ -0,0 +1,301 @@
+use crate::{core, flow};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct Reduce<ParallelFlow, Subscription, Item, Error, Acc, InitialFn, Reducer> {
+    upstream: ParallelFlow,
+    initial_fn: InitialFn,
+    reducer: Reducer,
+    phantom: PhantomData<(Subscription, Item, Error, Acc)>,
+}
+
+#[async_trait]
+impl<ParallelFlow, Subscription, Item, Error, Acc, InitialFn, Reducer>
+    core::ParallelFlow<ReduceSubscription<Subscription, Acc, Error>, Acc, Error>
+    for Reduce<ParallelFlow, Subscription, Item, Error, Acc, InitialFn, Reducer>
+where
+    ParallelFlow: core::ParallelFlow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Acc: Send + 'static,
+    InitialFn: Fn() -> Acc + Send + Sync + 'static,
+    Reducer: Fn(Acc, Item) -> Acc + Send + Sync + 'static,
+{
+    fn rails(&self) -> usize {
+        self.upstream.rails()
+    }
+
+    async fn subscribe<Subscriber>(self, subscribers: Vec<Subscriber>)
+    where
+        Subscriber: core::Subscriber<ReduceSubscription<Subscription, Acc, Error>, Acc, Error>
+            + Send
+            + 'static,
+    {
+        let initial_fn = Arc::new(self.initial_fn);
+        let reducer = Arc::new(self.reducer);
+        let subscribers = subscribers
+            .into_iter()
+            .map(|subscriber| {
+                ReduceSubscriber::new(subscriber, initial_fn.clone(), reducer.clone())
+            })
+            .collect();
+        self.upstream.subscribe(subscribers).await;
+    }
+}
+
+type BoxedSubscriber<Subscription, Acc, Error> = Box<
+    dyn core::Subscriber<ReduceSubscription<Subscription, Acc, Error>, Acc, Error>
+        + Send
+        + 'static,
+>;
+
+struct Data<Subscription, Acc, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, Acc, Error>>>,
+    state: parking_lot::Mutex<State<Acc>>,
+}
+
+// The reduced result of a rail is held back until the rail has been requested.
+struct State<Acc> {
+    requested: bool,
+    completed: bool,
+    result: Option<Acc>,
+}
+
+impl<Subscription, Acc, Error> Data<Subscription, Acc, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Acc: Send + 'static,
+    Error: Send + 'static,
+{
+    // Emits the result and completes, once the rail has both completed and been requested.
+    async fn try_emit(&self) {
+        let result = {
+            let mut state = self.state.lock();
+            if !state.requested || !state.completed {
+                return;
+            }
+            state.completed = false;
+            state.result.take()
+        };
+        let subscriber = self.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            if let Some(result) = result {
+                subscriber.on_next(result).await;
+            }
+            subscriber.on_completed().await;
+        }
+    }
+}
+
+struct ReduceSubscriber<Subscription, Item, Error, Acc, InitialFn, Reducer> {
+    data: Arc<Data<Subscription, Acc, Error>>,
+    initial_fn: Arc<InitialFn>,
+    reducer: Arc<Reducer>,
+    acc: Option<Acc>,
+    phantom: PhantomData<Item>,
+}
+
+impl<Subscription, Item, Error, Acc, InitialFn, Reducer>
+    ReduceSubscriber<Subscription, Item, Error, Acc, InitialFn, Reducer>
+{
+    fn new<Subscriber>(
+        subscriber: Subscriber,
+        initial_fn: Arc<InitialFn>,
+        reducer: Arc<Reducer>,
+    ) -> Self
+    where
+        Subscriber: core::Subscriber<ReduceSubscription<Subscription, Acc, Error>, Acc, Error>
+            + Send
+            + 'static,
+    {
+        Self {
+            data: Arc::new(Data {
+                subscriber: Mutex::new(Some(Box::new(subscriber))),
+                state: parking_lot::Mutex::new(State {
+                    requested: false,
+                    completed: false,
+                    result: None,
+                }),
+            }),
+            initial_fn,
+            reducer,
+            acc: None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Acc, InitialFn, Reducer> core::Subscriber<Subscription, Item, Error>
+    for ReduceSubscriber<Subscription, Item, Error, Acc, InitialFn, Reducer>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Acc: Send + 'static,
+    InitialFn: Fn() -> Acc + Send + Sync,
+    Reducer: Fn(Acc, Item) -> Acc + Send + Sync,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.acc = Some((self.initial_fn)());
+        let subscription = Arc::new(subscription);
+        // The subscriber is taken out of data during on_subscribe, so that a request from
+        // within on_subscribe does not emit the result before on_subscribe returns.
+        let subscriber = self.data.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber
+                .on_subscribe(ReduceSubscription::new(subscription.clone(), self.data.clone()))
+                .await;
+            *self.data.subscriber.lock().await = Some(subscriber);
+        }
+        if !subscription.try_request_now(usize::MAX) {
+            subscription.request(usize::MAX).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if let Some(acc) = self.acc.take() {
+            self.acc = Some((self.reducer)(acc, item));
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.acc = None;
+        let subscriber = self.data.subscriber.lock().await.take();
+        if let Some(mut subscriber) = subscriber {
+            subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        {
+            let mut state = self.data.state.lock();
+            state.result = self.acc.take();
+            state.completed = true;
+        }
+        self.data.try_emit().await;
+    }
+}
+
+/// The [`Subscription`] passed to the rails of [`Reduce`]. All items are requested from the
+/// upstream right away, while the single item of each rail is only emitted once the rail has
+/// requested at least one item.
+///
+/// [`Subscription`]: ../core/trait.Subscription.html
+/// [`Reduce`]: struct.Reduce.html
+pub struct ReduceSubscription<Subscription, Acc, Error> {
+    upstream: Arc<Subscription>,
+    data: Arc<Data<Subscription, Acc, Error>>,
+}
+
+impl<Subscription, Acc, Error> ReduceSubscription<Subscription, Acc, Error> {
+    fn new(upstream: Arc<Subscription>, data: Arc<Data<Subscription, Acc, Error>>) -> Self {
+        Self { upstream, data }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Acc, Error> core::Subscription for ReduceSubscription<Subscription, Acc, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Acc: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.upstream.cancel().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.upstream.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        if count > 0 {
+            self.data.state.lock().requested = true;
+            self.data.try_emit().await;
+        }
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        self.upstream.try_cancel_now()
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        // Emitting the result must be awaited, which is left to request.
+        let mut state = self.data.state.lock();
+        if count > 0 && !state.completed {
+            state.requested = true;
+        }
+        count == 0 || !state.completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn reduce() {
+        let rail0 = TestSubscriber::new(1);
+        let rail1 = TestSubscriber::new(1);
+        (0..6)
+            .into_flow()
+            .parallel(2)
+            .reduce(|| 0, |acc, a| acc + a)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        assert_eq!(rail0.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail0.items().await, vec![6]);
+        assert_eq!(rail1.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail1.items().await, vec![9]);
+    }
+
+    #[async_std::test]
+    async fn waits_for_request() {
+        let rail0 = TestSubscriber::default();
+        let rail1 = TestSubscriber::default();
+        (0..6)
+            .into_flow()
+            .parallel(2)
+            .reduce(|| 0, |acc, a| acc + a)
+            .subscribe(vec![rail0.clone(), rail1.clone()])
+            .await;
+
+        assert_eq!(rail0.status().await, SubscriberStatus::Subscribed);
+        assert_eq!(rail0.items().await, vec![]);
+        rail0.request_direct(1).await;
+        assert_eq!(rail0.status().await, SubscriberStatus::Completed);
+        assert_eq!(rail0.items().await, vec![6]);
+        assert_eq!(rail1.status().await, SubscriberStatus::Subscribed);
+        rail1.request_direct(1).await;
+        assert_eq!(rail1.items().await, vec![9]);
+    }
+
+    #[async_std::test]
+    async fn reduce_sequential() {
+        let test_subscriber = TestSubscriber::new(3);
+        (0..6)
+            .into_flow()
+            .parallel(3)
+            .reduce(Vec::new, |mut acc, a| {
+                acc.push(a);
+                acc
+            })
+            .sequential_ordered()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(
+            test_subscriber.items().await,
+            vec![vec![0, 3], vec![1, 4], vec![2, 5]]
+        );
+    }
+}
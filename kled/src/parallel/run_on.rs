@@ -0,0 +1,76 @@
+use crate::core;
+use crate::flow::operators::{ObserveOnSubscriber, ObserveOnSubscription};
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+#[derive(new)]
+pub struct RunOn<ParallelFlow, Subscription, Item, Error, Scheduler> {
+    upstream: ParallelFlow,
+    scheduler: Scheduler,
+    prefetch: usize,
+    phantom: PhantomData<(Subscription, Item, Error)>,
+}
+
+#[async_trait]
+impl<ParallelFlow, Subscription, Item, Error, Scheduler>
+    core::ParallelFlow<ObserveOnSubscription<Subscription, Item, Error, Scheduler>, Item, Error>
+    for RunOn<ParallelFlow, Subscription, Item, Error, Scheduler>
+where
+    ParallelFlow: core::ParallelFlow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+    Scheduler: core::Scheduler,
+{
+    fn rails(&self) -> usize {
+        self.upstream.rails()
+    }
+
+    async fn subscribe<Subscriber>(self, subscribers: Vec<Subscriber>)
+    where
+        Subscriber: core::Subscriber<
+                ObserveOnSubscription<Subscription, Item, Error, Scheduler>,
+                Item,
+                Error,
+            > + Send
+            + 'static,
+    {
+        let (scheduler, prefetch) = (self.scheduler, self.prefetch);
+        // Each rail drains its items in a separate task on the scheduler.
+        let subscribers = subscribers
+            .into_iter()
+            .map(|subscriber| {
+                ObserveOnSubscriber::new(subscriber, scheduler.clone(), prefetch, false)
+            })
+            .collect();
+        self.upstream.subscribe(subscribers).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::scheduler;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn run_on() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_subscriber = TestSubscriber::new(100);
+        (0..100)
+            .into_flow()
+            .parallel(4)
+            .run_on(scheduler.clone())
+            .map(|a| a * 2)
+            .sequential_ordered()
+            .subscribe(test_subscriber.clone())
+            .await;
+        scheduler.join();
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(
+            test_subscriber.items().await,
+            (0..100).map(|a| a * 2).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use crate::core;
+use crate::flow;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+#[chronobreak]
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+
+#[derive(new)]
+pub struct Sequential<ParallelFlow, Subscription, Item, Error> {
+    upstream: ParallelFlow,
+    prefetch: usize,
+    ordered: bool,
+    phantom: PhantomData<(Subscription, Item, Error)>,
+}
+
+#[async_trait]
+impl<ParallelFlow, Subscription, Item, Error>
+    core::Flow<SequentialSubscription<Subscription, Item, Error>, Item, Error>
+    for Sequential<ParallelFlow, Subscription, Item, Error>
+where
+    ParallelFlow: core::ParallelFlow<Subscription, Item, Error> + Send + 'static,
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn subscribe<Subscriber>(self, mut subscriber: Subscriber)
+    where
+        Subscriber: core::Subscriber<SequentialSubscription<Subscription, Item, Error>, Item, Error>
+            + Send
+            + 'static,
+    {
+        let rails = self.upstream.rails();
+        let data = Arc::new(Data::new(rails, self.prefetch, self.ordered));
+        // The subscriber is only stored in data after on_subscribe, so that a drain caused by a
+        // request from within on_subscribe does not emit items before on_subscribe returns.
+        subscriber
+            .on_subscribe(SequentialSubscription::new(data.clone()))
+            .await;
+        *data.subscriber.lock().await = Some(Box::new(subscriber));
+        let subscribers = (0..rails)
+            .map(|index| RailSubscriber::new(index, data.clone()))
+            .collect();
+        self.upstream.subscribe(subscribers).await;
+    }
+}
+
+type BoxedSubscriber<Subscription, Item, Error> = Box<
+    dyn core::Subscriber<SequentialSubscription<Subscription, Item, Error>, Item, Error>
+        + Send
+        + 'static,
+>;
+
+struct Data<Subscription, Item, Error> {
+    subscriber: Mutex<Option<BoxedSubscriber<Subscription, Item, Error>>>,
+    // The subscriptions of the rails, which are cloned out of the lock before being used, as
+    // the rails may emit items from within request.
+    upstreams: parking_lot::Mutex<Vec<Option<Arc<Subscription>>>>,
+    rails: parking_lot::Mutex<Rails<Item>>,
+    prefetch: usize,
+    ordered: bool,
+    requested: AtomicUsize,
+    cancelled: AtomicBool,
+    failed: AtomicBool,
+    wip: AtomicUsize,
+    error: parking_lot::Mutex<Option<flow::Error<Error>>>,
+}
+
+struct Rails<Item> {
+    queues: Vec<VecDeque<Item>>,
+    completed: Vec<bool>,
+    // The number of items taken from each rail since items were last requested from it.
+    consumed: Vec<usize>,
+    // The number of taken items after which the same number is requested from the rail.
+    limit: usize,
+    // The rail from which the next item is taken.
+    cursor: usize,
+}
+
+impl<Item> Rails<Item> {
+    fn new(rails: usize, prefetch: usize) -> Self {
+        Self {
+            queues: (0..rails).map(|_| VecDeque::new()).collect(),
+            completed: vec![false; rails],
+            consumed: vec![0; rails],
+            limit: prefetch - prefetch / 4,
+            cursor: 0,
+        }
+    }
+
+    // Takes the next item and returns it together with the rail it was taken from and whether
+    // items should be requested from that rail again.
+    fn poll(&mut self, ordered: bool) -> Option<(Item, usize, bool)> {
+        let rails = self.queues.len();
+        for _ in 0..rails {
+            let rail = self.cursor;
+            if let Some(item) = self.queues[rail].pop_front() {
+                self.cursor = (rail + 1) % rails;
+                self.consumed[rail] += 1;
+                let replenish = self.consumed[rail] == self.limit;
+                if replenish {
+                    self.consumed[rail] = 0;
+                }
+                return Some((item, rail, replenish));
+            }
+            // In ordered mode, the items are taken in strict round-robin order, so that only
+            // completed rails may be skipped.
+            if ordered && !self.completed[rail] {
+                return None;
+            }
+            self.cursor = (rail + 1) % rails;
+        }
+        None
+    }
+
+    fn is_done(&self) -> bool {
+        self.completed.iter().all(|completed| *completed)
+            && self.queues.iter().all(VecDeque::is_empty)
+    }
+
+    fn clear(&mut self) {
+        self.queues.iter_mut().for_each(VecDeque::clear);
+    }
+}
+
+impl<Subscription, Item, Error> Data<Subscription, Item, Error> {
+    fn new(rails: usize, prefetch: usize, ordered: bool) -> Self {
+        Self {
+            subscriber: Mutex::new(None),
+            upstreams: parking_lot::Mutex::new((0..rails).map(|_| None).collect()),
+            rails: parking_lot::Mutex::new(Rails::new(rails, prefetch)),
+            prefetch,
+            ordered,
+            requested: AtomicUsize::default(),
+            cancelled: AtomicBool::new(false),
+            failed: AtomicBool::new(false),
+            wip: AtomicUsize::default(),
+            error: parking_lot::Mutex::new(None),
+        }
+    }
+}
+
+impl<Subscription, Item, Error> Data<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel_rails(&self, except: Option<usize>) {
+        let upstreams: Vec<_> = self
+            .upstreams
+            .lock()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != except)
+            .filter_map(|(_, upstream)| upstream.clone())
+            .collect();
+        for upstream in upstreams {
+            upstream.cancel().await;
+        }
+    }
+
+    async fn request_rail(&self, index: usize, count: usize) {
+        let upstream = self.upstreams.lock()[index].clone();
+        if let Some(upstream) = upstream {
+            if !upstream.try_request_now(count) {
+                upstream.request(count).await;
+            }
+        }
+    }
+
+    // Emits the queued items and the terminal signal unless a drain is already in progress, in
+    // which case only wip is increased, which causes the active drain to loop once more.
+    async fn drain(&self) {
+        if self.wip.fetch_add(1, Ordering::SeqCst) != 0 {
+            return;
+        }
+        let mut missed = 1;
+        loop {
+            let mut subscriber = self.subscriber.lock().await;
+            while let Some(current) = subscriber.as_mut() {
+                if self.cancelled.load(Ordering::SeqCst) {
+                    self.rails.lock().clear();
+                    *subscriber = None;
+                    break;
+                }
+                let error = self.error.lock().take();
+                if let Some(error) = error {
+                    self.rails.lock().clear();
+                    current.on_error(error).await;
+                    *subscriber = None;
+                    break;
+                }
+                if self.requested.load(Ordering::SeqCst) > 0 {
+                    let polled = self.rails.lock().poll(self.ordered);
+                    if let Some((item, rail, replenish)) = polled {
+                        self.requested.fetch_sub(1, Ordering::SeqCst);
+                        current.on_next(item).await;
+                        if replenish {
+                            let limit = self.rails.lock().limit;
+                            self.request_rail(rail, limit).await;
+                        }
+                        continue;
+                    }
+                }
+                let done = self.rails.lock().is_done();
+                if done {
+                    current.on_completed().await;
+                    *subscriber = None;
+                }
+                break;
+            }
+            drop(subscriber);
+            missed = self.wip.fetch_sub(missed, Ordering::SeqCst) - missed;
+            if missed == 0 {
+                break;
+            }
+        }
+    }
+}
+
+#[derive(new)]
+struct RailSubscriber<Subscription, Item, Error> {
+    index: usize,
+    data: Arc<Data<Subscription, Item, Error>>,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscriber<Subscription, Item, Error>
+    for RailSubscriber<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        let subscription = Arc::new(subscription);
+        self.data.upstreams.lock()[self.index] = Some(subscription.clone());
+        if self.data.cancelled.load(Ordering::SeqCst) || self.data.failed.load(Ordering::SeqCst)
+        {
+            subscription.cancel().await;
+        } else if !subscription.try_request_now(self.data.prefetch) {
+            subscription.request(self.data.prefetch).await;
+        }
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.data.cancelled.load(Ordering::SeqCst) || self.data.failed.load(Ordering::SeqCst)
+        {
+            return;
+        }
+        self.data.rails.lock().queues[self.index].push_back(item);
+        self.data.drain().await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        // Only the first error is emitted, after which the other rails are cancelled.
+        if !self.data.failed.swap(true, Ordering::SeqCst) {
+            *self.data.error.lock() = Some(error);
+            self.data.cancel_rails(Some(self.index)).await;
+            self.data.drain().await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        self.data.rails.lock().completed[self.index] = true;
+        self.data.drain().await;
+    }
+}
+
+/// The [`Subscription`] of a [`Flow`] created via [`sequential`] or [`sequential_ordered`].
+/// Cancelling it cancels all rails of the upstream [`ParallelFlow`].
+///
+/// [`Subscription`]: ../core/trait.Subscription.html
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`sequential`]: ../core/trait.ParallelFlow.html#method.sequential
+/// [`sequential_ordered`]: ../core/trait.ParallelFlow.html#method.sequential_ordered
+/// [`ParallelFlow`]: ../core/trait.ParallelFlow.html
+pub struct SequentialSubscription<Subscription, Item, Error> {
+    data: Arc<Data<Subscription, Item, Error>>,
+}
+
+impl<Subscription, Item, Error> SequentialSubscription<Subscription, Item, Error> {
+    fn new(data: Arc<Data<Subscription, Item, Error>>) -> Self {
+        Self { data }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error> core::Subscription
+    for SequentialSubscription<Subscription, Item, Error>
+where
+    Subscription: core::Subscription + Send + Sync + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn cancel(&self) {
+        self.data.cancelled.store(true, Ordering::SeqCst);
+        self.data.cancel_rails(None).await;
+        self.data.drain().await;
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn request(&self, count: usize) {
+        self.data.requested.fetch_add(count, Ordering::SeqCst);
+        self.data.drain().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn sequential() {
+        let test_subscriber = TestSubscriber::new(10);
+        (0..6)
+            .into_flow()
+            .parallel(3)
+            .map(|a| a * 2)
+            .sequential()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        let mut items = test_subscriber.items().await;
+        items.sort_unstable();
+        assert_eq!(items, vec![0, 2, 4, 6, 8, 10]);
+    }
+
+    #[async_std::test]
+    async fn sequential_ordered() {
+        let test_subscriber = TestSubscriber::new(10);
+        (0..7)
+            .into_flow()
+            .parallel(3)
+            .map(|a| a + 1)
+            .sequential_ordered()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[async_std::test]
+    async fn respects_demand() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let test_subscriber = TestSubscriber::new(1);
+        test_flow
+            .clone()
+            .parallel(2)
+            .sequential_ordered()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        test_flow.emit_all(0..3).await;
+        assert_eq!(test_subscriber.items().await, vec![0]);
+        test_subscriber.request_direct(2).await;
+        assert_eq!(test_subscriber.items().await, vec![0, 1, 2]);
+        test_flow.emit_completed().await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+    }
+
+    #[async_std::test]
+    async fn error_cancels_rails() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let test_subscriber = TestSubscriber::new(10);
+        test_flow
+            .clone()
+            .parallel(2)
+            .sequential()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        test_flow.emit(0).await;
+        test_flow.emit_error(()).await;
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Error);
+        assert_eq!(test_subscriber.error().await, Some(flow::Error::Upstream(())));
+        assert_eq!(test_subscriber.items().await, vec![0]);
+    }
+
+    #[async_std::test]
+    async fn cancel() {
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let mut test_subscriber = TestSubscriber::new(10);
+        test_flow
+            .clone()
+            .parallel(2)
+            .sequential()
+            .subscribe(test_subscriber.clone())
+            .await;
+
+        test_subscriber.cancel().await;
+        assert!(test_flow.is_cancelled().await);
+    }
+}
@@ -1,4 +1,5 @@
 pub use crate::core::*;
 pub use crate::flow;
 pub use crate::observable;
+pub use crate::parallel;
 pub use crate::scheduler;
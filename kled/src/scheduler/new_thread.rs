@@ -1,4 +1,4 @@
-use crate::core;
+use crate::{core, hooks, metrics};
 use async_std::task;
 use std::future::Future;
 
@@ -34,10 +34,15 @@ impl core::Scheduler for NewThreadScheduler {
     where
         Fut: Future<Output = ()> + Send + 'static,
     {
+        let future = hooks::on_schedule(future);
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, tracing::Span::current());
         self.data.active_count.fetch_add(1, Ordering::SeqCst);
+        metrics::adjust_gauge("active_tasks", "new_thread", 1);
         let data = self.data.clone();
         thread::spawn(move || {
             task::block_on(future);
+            metrics::adjust_gauge("active_tasks", "new_thread", -1);
             data.active_count.fetch_sub(1, Ordering::SeqCst);
             if !data.has_work() {
                 let _ = data.join_mutex.lock();
@@ -69,3 +74,24 @@ impl Data {
         self.active_count.load(Ordering::SeqCst) > 0
     }
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::scheduler::*;
+    use crate::tracing_recorder::*;
+
+    #[test]
+    fn propagates_current_span() {
+        let recorder = test_recorder();
+        let scheduler = NewThreadScheduler::default();
+        let span = tracing::debug_span!("scheduler", name = "new_thread");
+        span.in_scope(|| scheduler.schedule(async { tracing::debug!("scheduled") }));
+        scheduler.join();
+
+        assert_eq!(
+            recorder.events("scheduler", "new_thread"),
+            vec![RecordedEvent::new("scheduled", None)]
+        );
+    }
+}
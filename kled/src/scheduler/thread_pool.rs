@@ -1,4 +1,4 @@
-use crate::core;
+use crate::{core, hooks, metrics};
 use std::cell::UnsafeCell;
 use std::future::Future;
 
@@ -60,11 +60,16 @@ impl core::Scheduler for ThreadPoolScheduler {
     where
         Fut: Future<Output = ()> + Send + 'static,
     {
+        let future = hooks::on_schedule(future);
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, tracing::Span::current());
         self.data.job_count.fetch_add(1, Ordering::SeqCst);
+        metrics::adjust_gauge("active_tasks", "thread_pool", 1);
         self.thread_pool.spawn_ok(async move {
             future.await;
 
             let data = DATA.with(|data| unsafe { &*data.get() }.as_ref().unwrap());
+            metrics::adjust_gauge("active_tasks", "thread_pool", -1);
             data.job_count.fetch_sub(1, Ordering::SeqCst);
             if !data.has_work() {
                 let _ = data.join_mutex.lock();
@@ -96,3 +101,24 @@ impl Data {
         self.job_count.load(Ordering::SeqCst) > 0
     }
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::scheduler::*;
+    use crate::tracing_recorder::*;
+
+    #[test]
+    fn propagates_current_span() {
+        let recorder = test_recorder();
+        let scheduler = ThreadPoolScheduler::default();
+        let span = tracing::debug_span!("scheduler", name = "thread_pool");
+        span.in_scope(|| scheduler.schedule(async { tracing::debug!("scheduled") }));
+        scheduler.join();
+
+        assert_eq!(
+            recorder.events("scheduler", "thread_pool"),
+            vec![RecordedEvent::new("scheduled", None)]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{core, flow};
 use crate::subscription::*;
+use crate::hooks::{AssemblyTrace, Demand};
 use async_trait::async_trait;
 
 pub struct LambdaSubscriber<Subscription, NextFn, ErrorFn, CompletedFn>
@@ -10,6 +11,7 @@ where
     item_consumer: NextFn,
     error_consumer: ErrorFn,
     completed_consumer: CompletedFn,
+    assembly: AssemblyTrace,
 }
 
 impl<Subscription, NextFn, ErrorFn, CompletedFn>
@@ -27,12 +29,17 @@ where
             item_consumer,
             error_consumer,
             completed_consumer,
+            assembly: AssemblyTrace::take(),
         }
     }
 
     pub fn subscription(&self) -> LazySubscription<Subscription> {
         self.stub.subscription()
     }
+
+    pub fn demand(&self) -> Option<Demand> {
+        self.stub.demand()
+    }
 }
 
 #[async_trait]
@@ -52,21 +59,25 @@ where
     }
 
     async fn on_next(&mut self, item: Item) {
-        (self.item_consumer)(item)
+        let item_consumer = &mut self.item_consumer;
+        self.assembly.scope(|| item_consumer(item))
     }
 
     async fn on_error(&mut self, error: flow::Error<Error>) {
-        (self.error_consumer)(error)
+        let error_consumer = &mut self.error_consumer;
+        self.assembly.scope(|| error_consumer(error))
     }
 
     async fn on_completed(&mut self) {
-        (self.completed_consumer)()
+        self.assembly.scope(&mut self.completed_consumer)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::flow::*;
     use crate::prelude::*;
+    use crate::scheduler;
 
     #[async_std::test]
     async fn subscribe_next() {
@@ -77,4 +88,20 @@ mod tests {
         })
         .await;
     }
+
+    #[async_std::test]
+    async fn subscribe_scoped() {
+        let scheduler = scheduler::ThreadPoolScheduler::default();
+        let test_flow = TestFlow::default()
+            .annotate_item_type(())
+            .annotate_error_type(());
+        let guard = test_flow
+            .clone()
+            .subscribe_scoped(|_| {}, scheduler.clone())
+            .await;
+        assert!(!test_flow.is_cancelled().await);
+        drop(guard);
+        scheduler.join();
+        assert!(test_flow.is_cancelled().await);
+    }
 }
@@ -1,5 +1,10 @@
 reexport_all! {
     mod lambda_subscriber;
+    mod observer_as_subscriber;
+    mod raw_as_subscriber;
+    mod safe_subscriber;
     mod scheduled_subscriber;
+    mod subscriber_as_observer;
+    mod subscriber_as_raw;
     mod test_subscriber;
 }
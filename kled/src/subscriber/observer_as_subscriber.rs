@@ -0,0 +1,76 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use std::task::{Context, Poll};
+
+/// Adapts an [`Observer`] whose error type is `flow::Error<Error>` to the [`Subscriber`] trait.
+/// The subscription is passed to the observer unchanged, so it may still request items from
+/// and cancel the upstream flow.
+///
+/// Together with [`SubscriberAsObserver`] this allows operators declared via
+/// `#[operator(type = "both")]` to reuse their observer for flows.
+///
+/// [`Observer`]: ../core/trait.Observer.html
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+/// [`SubscriberAsObserver`]: struct.SubscriberAsObserver.html
+pub struct ObserverAsSubscriber<Observer> {
+    observer: Observer,
+}
+
+impl<Observer> ObserverAsSubscriber<Observer> {
+    pub fn new(observer: Observer) -> Self {
+        Self { observer }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Observer> core::Subscriber<Subscription, Item, Error>
+    for ObserverAsSubscriber<Observer>
+where
+    Observer: core::Observer<Subscription, Item, flow::Error<Error>> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.observer.on_subscribe(subscription).await
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.observer.on_next(item).await
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.observer.on_error(error).await
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await
+    }
+}
+
+impl<Subscription, Item, Error, Observer> core::RawSubscriber<Subscription, Item, Error>
+    for ObserverAsSubscriber<Observer>
+where
+    Observer: core::RawObserver<Subscription, Item, flow::Error<Error>>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.observer.poll_ready(cx)
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.observer.start_subscribe(subscription);
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.observer.start_next(item);
+    }
+
+    fn start_error(&mut self, error: flow::Error<Error>) {
+        self.observer.start_error(error);
+    }
+
+    fn start_completed(&mut self) {
+        self.observer.start_completed();
+    }
+}
@@ -0,0 +1,53 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use futures::future;
+
+/// Adapts a [`RawSubscriber`] to the [`Subscriber`] trait. Each signal is started once the raw
+/// subscriber is ready and then polled until it has been processed.
+///
+/// [`RawSubscriber`]: ../core/trait.RawSubscriber.html
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+pub struct RawAsSubscriber<Subscriber> {
+    subscriber: Subscriber,
+}
+
+impl<Subscriber> RawAsSubscriber<Subscriber> {
+    pub fn new(subscriber: Subscriber) -> Self {
+        Self { subscriber }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> core::Subscriber<Subscription, Item, Error>
+    for RawAsSubscriber<Subscriber>
+where
+    Subscriber: core::RawSubscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+        self.subscriber.start_subscribe(subscription);
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+        self.subscriber.start_next(item);
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+        self.subscriber.start_error(error);
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+    }
+
+    async fn on_completed(&mut self) {
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+        self.subscriber.start_completed();
+        future::poll_fn(|cx| self.subscriber.poll_ready(cx)).await;
+    }
+}
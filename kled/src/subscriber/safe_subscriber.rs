@@ -0,0 +1,131 @@
+use crate::hooks::{self, Demand, ProtocolState, ProtocolViolation};
+use crate::{core, flow};
+use async_trait::async_trait;
+
+/// A [`Subscriber`] which enforces the grammar `on_subscribe on_next* (on_error | on_completed)?`
+/// for the wrapped subscriber. Signals emitted after `on_error` or `on_completed` are dropped,
+/// and violations are reported to [`hooks::on_protocol_violation`]. If created via
+/// [`with_demand`] with a [`Demand`], it also checks that no more items are emitted than were
+/// added to it. Items emitted without demand are still forwarded. The subscription is passed to
+/// the wrapped subscriber unchanged.
+///
+/// The checks are only performed if they were enabled via [`hooks::set_protocol_checks`] when
+/// the `SafeSubscriber` was created. `subscribe_next` and `subscribe_all` of [`Flow`] wrap
+/// their subscribers in a `SafeSubscriber` which checks the demand requested via the
+/// returned subscription.
+///
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+/// [`with_demand`]: struct.SafeSubscriber.html#method.with_demand
+/// [`Demand`]: ../hooks/struct.Demand.html
+/// [`Flow`]: ../core/trait.Flow.html
+/// [`hooks::on_protocol_violation`]: ../hooks/fn.on_protocol_violation.html
+/// [`hooks::set_protocol_checks`]: ../hooks/fn.set_protocol_checks.html
+pub struct SafeSubscriber<Subscriber> {
+    subscriber: Subscriber,
+    state: ProtocolState,
+    demand: Option<Demand>,
+}
+
+impl<Subscriber> SafeSubscriber<Subscriber> {
+    pub fn new(subscriber: Subscriber) -> Self {
+        Self {
+            subscriber,
+            state: ProtocolState::new(),
+            demand: None,
+        }
+    }
+
+    /// Creates a `SafeSubscriber` which additionally checks each item against `demand`, if
+    /// any, to which the subscription of `subscriber` adds the requested items.
+    pub fn with_demand(subscriber: Subscriber, demand: Option<Demand>) -> Self {
+        Self {
+            subscriber,
+            state: ProtocolState::new(),
+            demand,
+        }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> core::Subscriber<Subscription, Item, Error>
+    for SafeSubscriber<Subscriber>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        if self.state.begin_on_next() {
+            if self.state.is_enabled() {
+                if let Some(demand) = &self.demand {
+                    if !demand.take() {
+                        hooks::on_protocol_violation(ProtocolViolation::OnNextWithoutDemand);
+                    }
+                }
+            }
+            self.subscriber.on_next(item).await;
+            self.state.end_on_next();
+        }
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        if self.state.terminate("on_error") {
+            self.subscriber.on_error(error).await;
+        }
+    }
+
+    async fn on_completed(&mut self) {
+        if self.state.terminate("on_completed") {
+            self.subscriber.on_completed().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow::*;
+    use crate::hooks::{self, Demand, ProtocolViolation};
+    use crate::prelude::*;
+    use crate::subscriber::*;
+
+    #[async_std::test]
+    async fn forwards_items_within_demand() {
+        let violations = hooks::test_violations();
+        let test_subscriber = TestSubscriber::new(2);
+        let demand = Demand::default();
+        demand.add(2);
+        let test_flow = TestFlow::default().annotate_error_type(());
+        test_flow
+            .clone()
+            .subscribe(SafeSubscriber::with_demand(
+                test_subscriber.clone(),
+                Some(demand),
+            ))
+            .await;
+        test_flow.emit_all(vec![0, 1]).await;
+        test_flow.emit_completed().await;
+
+        assert_eq!(test_subscriber.status().await, SubscriberStatus::Completed);
+        assert_eq!(test_subscriber.items().await, vec![0, 1]);
+        assert_eq!(violations.on_current_thread(), vec![]);
+    }
+
+    #[async_std::test]
+    async fn reports_missing_demand() {
+        let violations = hooks::test_violations();
+        let test_flow = TestFlow::default().annotate_error_type(());
+        let subscription = test_flow.clone().subscribe_next(|_| {}).await;
+        subscription.request(1).await;
+        test_flow.emit_all(vec![0, 1]).await;
+
+        assert_eq!(
+            violations.on_current_thread(),
+            vec![ProtocolViolation::OnNextWithoutDemand]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core;
+use crate::flow;
+use async_trait::async_trait;
+use std::task::{Context, Poll};
+
+/// Adapts a [`Subscriber`] to the [`Observer`] trait with `flow::Error<Error>` as the error type.
+/// This is the counterpart of [`ObserverAsSubscriber`].
+///
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+/// [`Observer`]: ../core/trait.Observer.html
+/// [`ObserverAsSubscriber`]: struct.ObserverAsSubscriber.html
+pub struct SubscriberAsObserver<Subscriber> {
+    subscriber: Subscriber,
+}
+
+impl<Subscriber> SubscriberAsObserver<Subscriber> {
+    pub fn new(subscriber: Subscriber) -> Self {
+        Self { subscriber }
+    }
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> core::Observer<Subscription, Item, flow::Error<Error>>
+    for SubscriberAsObserver<Subscriber>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await
+    }
+}
+
+impl<Subscription, Item, Error, Subscriber> core::RawObserver<Subscription, Item, flow::Error<Error>>
+    for SubscriberAsObserver<Subscriber>
+where
+    Subscriber: core::RawSubscriber<Subscription, Item, Error>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.subscriber.poll_ready(cx)
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.start_subscribe(subscription);
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.subscriber.start_next(item);
+    }
+
+    fn start_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.start_error(error);
+    }
+
+    fn start_completed(&mut self) {
+        self.subscriber.start_completed();
+    }
+}
@@ -0,0 +1,85 @@
+use crate::core;
+use crate::flow;
+use futures::ready;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type PendingSignal<Subscriber> = Pin<Box<dyn Future<Output = Subscriber> + Send>>;
+
+/// Adapts an [`Subscriber`] to the [`RawSubscriber`] trait. The subscriber is moved into the future
+/// of the signal currently being processed and returned once it completes.
+///
+/// [`Subscriber`]: ../core/trait.Subscriber.html
+/// [`RawSubscriber`]: ../core/trait.RawSubscriber.html
+pub struct SubscriberAsRaw<Subscriber> {
+    subscriber: Option<Subscriber>,
+    pending: Option<PendingSignal<Subscriber>>,
+}
+
+impl<Subscriber> SubscriberAsRaw<Subscriber> {
+    pub fn new(subscriber: Subscriber) -> Self {
+        Self {
+            subscriber: Some(subscriber),
+            pending: None,
+        }
+    }
+
+    fn start<F, Fut>(&mut self, f: F)
+    where
+        F: FnOnce(Subscriber) -> Fut,
+        Fut: Future<Output = Subscriber> + Send + 'static,
+    {
+        let subscriber = self
+            .subscriber
+            .take()
+            .expect("poll_ready must return Poll::Ready before a signal is started");
+        self.pending = Some(Box::pin(f(subscriber)));
+    }
+}
+
+impl<Subscription, Item, Error, Subscriber> core::RawSubscriber<Subscription, Item, Error>
+    for SubscriberAsRaw<Subscriber>
+where
+    Subscriber: core::Subscriber<Subscription, Item, Error> + Send + 'static,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if let Some(pending) = &mut self.pending {
+            let subscriber = ready!(pending.as_mut().poll(cx));
+            self.pending = None;
+            self.subscriber = Some(subscriber);
+        }
+        Poll::Ready(())
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.start(|mut subscriber| async move {
+            subscriber.on_subscribe(subscription).await;
+            subscriber
+        });
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.start(|mut subscriber| async move {
+            subscriber.on_next(item).await;
+            subscriber
+        });
+    }
+
+    fn start_error(&mut self, error: flow::Error<Error>) {
+        self.start(|mut subscriber| async move {
+            subscriber.on_error(error).await;
+            subscriber
+        });
+    }
+
+    fn start_completed(&mut self) {
+        self.start(|mut subscriber| async move {
+            subscriber.on_completed().await;
+            subscriber
+        });
+    }
+}
@@ -1,5 +1,6 @@
-use crate::core;
+use crate::{core, metrics};
 use async_trait::async_trait;
+use std::convert::TryFrom;
 #[chronobreak]
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 #[chronobreak]
@@ -28,7 +29,9 @@ impl ArcSubscriptionStub {
     }
 
     pub fn get_and_reset_requested(&self) -> usize {
-        self.data.requested.swap(0, Ordering::Relaxed)
+        let requested = self.data.requested.swap(0, Ordering::Relaxed);
+        adjust_outstanding_demand(requested, 0);
+        requested
     }
 
     pub fn is_cancelled(&self) -> bool {
@@ -52,7 +55,17 @@ impl core::Subscription for ArcSubscription {
     }
 
     async fn request(&self, count: usize) {
-        self.data.requested.fetch_add(count, Ordering::Relaxed);
+        self.data.add_requested(count);
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        self.data.cancelled.store(true, Ordering::Relaxed);
+        true
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.data.add_requested(count);
+        true
     }
 }
 
@@ -60,3 +73,55 @@ struct Data {
     cancelled: AtomicBool,
     requested: AtomicUsize,
 }
+
+impl Data {
+    // The requested items saturate at usize::MAX, i.e. unbounded demand.
+    fn add_requested(&self, count: usize) {
+        let previous = self
+            .requested
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |requested| {
+                Some(requested.saturating_add(count))
+            })
+            .unwrap();
+        adjust_outstanding_demand(previous, previous.saturating_add(count));
+    }
+}
+
+impl Drop for Data {
+    fn drop(&mut self) {
+        adjust_outstanding_demand(self.requested.load(Ordering::Relaxed), 0);
+    }
+}
+
+// Adjusts the gauge by the change from `previous` to `current` requested items. Unbounded
+// demand, i.e. usize::MAX, is not outstanding demand and thus reported as none.
+fn adjust_outstanding_demand(previous: usize, current: usize) {
+    let outstanding = |requested: usize| {
+        if requested == usize::MAX {
+            0
+        } else {
+            i64::try_from(requested).unwrap_or(i64::MAX)
+        }
+    };
+    let delta = outstanding(current) - outstanding(previous);
+    if delta != 0 {
+        metrics::adjust_gauge("outstanding_demand", "arc_subscription", delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscription::*;
+
+    #[async_std::test]
+    async fn saturates_requested() {
+        let stub = ArcSubscriptionStub::default();
+        let subscription = stub.subscription();
+        subscription.request(2).await;
+        subscription.request(usize::MAX).await;
+        assert!(subscription.try_request_now(1));
+        assert_eq!(stub.get_and_reset_requested(), usize::MAX);
+        assert_eq!(stub.get_and_reset_requested(), 0);
+    }
+}
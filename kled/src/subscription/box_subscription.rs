@@ -0,0 +1,43 @@
+use crate::core;
+use async_trait::async_trait;
+
+/// A type-erased [`Subscription`].
+///
+/// [`Subscription`]: ../core/trait.Subscription.html
+pub struct BoxSubscription {
+    inner: Box<dyn core::Subscription + Send + Sync>,
+}
+
+impl BoxSubscription {
+    pub fn new<Subscription>(subscription: Subscription) -> Self
+    where
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        Self {
+            inner: Box::new(subscription),
+        }
+    }
+}
+
+#[async_trait]
+impl core::Subscription for BoxSubscription {
+    async fn cancel(&self) {
+        self.inner.cancel().await
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled().await
+    }
+
+    async fn request(&self, count: usize) {
+        self.inner.request(count).await
+    }
+
+    fn try_cancel_now(&self) -> bool {
+        self.inner.try_cancel_now()
+    }
+
+    fn try_request_now(&self, count: usize) -> bool {
+        self.inner.try_request_now(count)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::core;
+use crate::subscription::BoxSubscription;
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+#[chronobreak]
+use std::sync::Arc;
+
+/// A [`Subscription`] which groups any number of subscriptions. Cancelling it cancels all of
+/// them and requests are forwarded to each of them. Subscriptions added after the group has
+/// been cancelled are cancelled immediately.
+///
+/// [`Subscription`]: ../core/trait.Subscription.html
+#[derive(Clone)]
+pub struct CompositeSubscription {
+    data: Arc<Mutex<Data>>,
+}
+
+impl Default for CompositeSubscription {
+    fn default() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Data::default())),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Data {
+    cancelled: bool,
+    subscriptions: Vec<Arc<BoxSubscription>>,
+}
+
+impl CompositeSubscription {
+    pub async fn add<Subscription>(&self, subscription: Subscription)
+    where
+        Subscription: core::Subscription + Send + Sync + 'static,
+    {
+        let mut data = self.data.lock().await;
+        if data.cancelled {
+            drop(data);
+            subscription.cancel().await;
+        } else {
+            data.subscriptions
+                .push(Arc::new(BoxSubscription::new(subscription)));
+        }
+    }
+
+    /// Removes all subscriptions from the group without cancelling them.
+    pub async fn clear(&self) {
+        self.data.lock().await.subscriptions.clear();
+    }
+
+    pub async fn len(&self) -> usize {
+        self.data.lock().await.subscriptions.len()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+#[async_trait]
+impl core::Subscription for CompositeSubscription {
+    async fn cancel(&self) {
+        let subscriptions = {
+            let mut data = self.data.lock().await;
+            data.cancelled = true;
+            std::mem::take(&mut data.subscriptions)
+        };
+        for subscription in subscriptions {
+            subscription.cancel().await;
+        }
+    }
+
+    async fn is_cancelled(&self) -> bool {
+        self.data.lock().await.cancelled
+    }
+
+    // The subscriptions are cloned out of the lock, as a request may cause items to be emitted
+    // synchronously, which in turn may add further subscriptions.
+    async fn request(&self, count: usize) {
+        let subscriptions = self.data.lock().await.subscriptions.clone();
+        for subscription in subscriptions {
+            subscription.request(count).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::subscription::*;
+
+    #[async_std::test]
+    async fn request_and_cancel() {
+        let stub1 = ArcSubscriptionStub::default();
+        let stub2 = ArcSubscriptionStub::default();
+        let composite = CompositeSubscription::default();
+        composite.add(stub1.subscription()).await;
+        composite.add(stub2.subscription()).await;
+        composite.request(3).await;
+        assert_eq!(stub1.get_and_reset_requested(), 3);
+        assert_eq!(stub2.get_and_reset_requested(), 3);
+        composite.cancel().await;
+        assert!(composite.is_cancelled().await);
+        assert!(stub1.is_cancelled());
+        assert!(stub2.is_cancelled());
+    }
+
+    #[async_std::test]
+    async fn add_after_cancel() {
+        let stub = ArcSubscriptionStub::default();
+        let composite = CompositeSubscription::default();
+        composite.cancel().await;
+        composite.add(stub.subscription()).await;
+        assert!(stub.is_cancelled());
+        assert!(composite.is_empty().await);
+    }
+}
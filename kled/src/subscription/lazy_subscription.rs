@@ -1,4 +1,5 @@
 use crate::core;
+use crate::hooks::{self, Demand};
 use async_std::sync::Mutex;
 use async_trait::async_trait;
 #[chronobreak]
@@ -6,12 +7,20 @@ use std::sync::Arc;
 
 pub struct LazySubscriptionStub<Subscription> {
     data: Arc<Mutex<Data<Subscription>>>,
+    // Only tracked if the protocol checks are enabled, as it is only read by SafeSubscriber.
+    demand: Option<Demand>,
 }
 
 impl<Subscription> Default for LazySubscriptionStub<Subscription> {
     fn default() -> Self {
+        let demand = if hooks::protocol_checks_enabled() {
+            Some(Demand::default())
+        } else {
+            None
+        };
         Self {
             data: Arc::new(Mutex::new(Data::default())),
+            demand,
         }
     }
 }
@@ -23,9 +32,18 @@ where
     pub fn subscription(&self) -> LazySubscription<Subscription> {
         LazySubscription {
             data: self.data.clone(),
+            demand: self.demand.clone(),
         }
     }
 
+    /// Returns the [`Demand`] to which the subscriptions of this stub add all requested items,
+    /// if the protocol checks were enabled when the stub was created.
+    ///
+    /// [`Demand`]: ../hooks/struct.Demand.html
+    pub fn demand(&self) -> Option<Demand> {
+        self.demand.clone()
+    }
+
     pub async fn set_subscription(&mut self, subscription: Subscription) {
         let mut data = self.data.lock().await;
         if data.cancelled {
@@ -40,6 +58,7 @@ where
 #[derive(Clone)]
 pub struct LazySubscription<Subscription> {
     data: Arc<Mutex<Data<Subscription>>>,
+    demand: Option<Demand>,
 }
 
 #[async_trait]
@@ -66,6 +85,10 @@ where
     }
 
     async fn request(&self, count: usize) {
+        // The demand is added before requesting, as the upstream may emit synchronously.
+        if let Some(demand) = &self.demand {
+            demand.add(count);
+        }
         let mut data = self.data.lock().await;
         if let Some(subscription) = &data.subscription {
             subscription.request(count).await;
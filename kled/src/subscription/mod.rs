@@ -1,4 +1,6 @@
 reexport_all! {
     mod arc_subscription;
+    mod box_subscription;
+    mod composite_subscription;
     mod lazy_subscription;
 }
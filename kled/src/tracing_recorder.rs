@@ -0,0 +1,180 @@
+// A tracing subscriber for the tests of the `tracing` feature, which records the events
+// emitted within each span.
+
+use parking_lot::Mutex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+#[chronobreak]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[chronobreak]
+use std::sync::Arc;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Current, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+thread_local! {
+    static ENTERED: RefCell<Vec<Id>> = RefCell::new(vec![]);
+}
+
+/// An event recorded by the [`Recorder`], i.e. its message and the `count` field, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RecordedEvent {
+    pub(crate) message: String,
+    pub(crate) count: Option<u64>,
+}
+
+impl RecordedEvent {
+    pub(crate) fn new(message: &str, count: Option<u64>) -> Self {
+        Self {
+            message: message.to_owned(),
+            count,
+        }
+    }
+}
+
+struct Span {
+    metadata: &'static Metadata<'static>,
+    // The value of the `name` field, which identifies the span in the tests.
+    name: Option<String>,
+    events: Vec<RecordedEvent>,
+}
+
+#[derive(Default)]
+struct Fields {
+    name: Option<String>,
+    message: Option<String>,
+    count: Option<u64>,
+}
+
+impl Visit for Fields {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "count" {
+            self.count = Some(value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.name = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = Some(format!("{:?}", value)),
+            "name" => self.name = Some(format!("{:?}", value)),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Recorder {
+    data: Arc<Data>,
+}
+
+struct Data {
+    next_id: AtomicU64,
+    spans: Mutex<HashMap<u64, Span>>,
+}
+
+impl Recorder {
+    /// Returns the events recorded within the spans called `span` whose `name` field is `name`.
+    pub(crate) fn events(&self, span: &str, name: &str) -> Vec<RecordedEvent> {
+        self.data
+            .spans
+            .lock()
+            .values()
+            .filter(|s| s.metadata.name() == span && s.name.as_deref() == Some(name))
+            .flat_map(|s| s.events.iter().cloned())
+            .collect()
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        attributes.record(&mut fields);
+        let id = self.data.next_id.fetch_add(1, Ordering::Relaxed);
+        let span = Span {
+            metadata: attributes.metadata(),
+            name: fields.name,
+            events: vec![],
+        };
+        self.data.spans.lock().insert(id, span);
+        Id::from_u64(id)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let parent = if event.is_contextual() {
+            ENTERED.with(|entered| entered.borrow().last().cloned())
+        } else {
+            event.parent().cloned()
+        };
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        if let Some(parent) = parent {
+            if let Some(span) = self.data.spans.lock().get_mut(&parent.into_u64()) {
+                let message = fields.message.unwrap_or_default();
+                span.events.push(RecordedEvent {
+                    message,
+                    count: fields.count,
+                });
+            }
+        }
+    }
+
+    fn enter(&self, id: &Id) {
+        ENTERED.with(|entered| entered.borrow_mut().push(id.clone()));
+    }
+
+    fn exit(&self, id: &Id) {
+        ENTERED.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            if let Some(index) = entered.iter().rposition(|entered| entered == id) {
+                entered.remove(index);
+            }
+        });
+    }
+
+    fn current_span(&self) -> Current {
+        let current = ENTERED.with(|entered| entered.borrow().last().cloned());
+        let metadata = current.as_ref().and_then(|id| {
+            let spans = self.data.spans.lock();
+            spans.get(&id.into_u64()).map(|span| span.metadata)
+        });
+        match (current, metadata) {
+            (Some(id), Some(metadata)) => Current::new(id, metadata),
+            _ => Current::none(),
+        }
+    }
+}
+
+// The recorder is installed once as the global default, as the events of futures run by the
+// schedulers are emitted on other threads. Tests should thus use names unique to them.
+pub(crate) fn test_recorder() -> Recorder {
+    static TEST_RECORDER: Mutex<Option<Recorder>> = parking_lot::const_mutex(None);
+    TEST_RECORDER
+        .lock()
+        .get_or_insert_with(|| {
+            let recorder = Recorder {
+                data: Arc::new(Data {
+                    next_id: AtomicU64::new(1),
+                    spans: Mutex::new(HashMap::new()),
+                }),
+            };
+            tracing::subscriber::set_global_default(recorder.clone())
+                .expect("a global tracing subscriber was already set");
+            recorder
+        })
+        .clone()
+}
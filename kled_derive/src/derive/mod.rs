@@ -1,14 +1,27 @@
 use darling::FromMeta;
 use proc_macro::TokenStream;
+use proc_macro2::{Group, TokenTree};
 use proc_macro_error::abort;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, parse_quote, parse_str, Fields, Ident, ItemStruct};
+use syn::{
+    parse_macro_input, parse_quote, parse_str, Fields, GenericParam, Ident, Index, ItemStruct,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, FromMeta)]
 #[darling(default)]
 pub enum UpstreamTy {
     Observable,
     Flow,
+    Both,
+}
+
+impl UpstreamTy {
+    fn kinds(&self) -> Vec<UpstreamTy> {
+        match self {
+            UpstreamTy::Both => vec![UpstreamTy::Observable, UpstreamTy::Flow],
+            kind => vec![kind.clone()],
+        }
+    }
 }
 
 #[derive(FromMeta)]
@@ -29,6 +42,10 @@ pub struct Args {
     item: Option<syn::LitStr>,
     #[darling(default)]
     error: Option<syn::LitStr>,
+    #[darling(default)]
+    raw: bool,
+    #[darling(default)]
+    method: Option<syn::LitStr>,
 }
 
 impl Args {
@@ -41,10 +58,10 @@ impl Args {
             })
     }
 
-    fn subscriber_trait(&self) -> Ident {
-        match self.upstream {
+    fn subscriber_trait(kind: &UpstreamTy) -> Ident {
+        match kind {
             UpstreamTy::Flow => format_ident!("Subscriber"),
-            UpstreamTy::Observable => format_ident!("Observer"),
+            _ => format_ident!("Observer"),
         }
     }
 
@@ -61,37 +78,116 @@ impl Args {
             .unwrap_or_else(f)
     }
 
-    fn upstream_params(&self) -> proc_macro2::TokenStream {
+    fn upstream_params(&self, kind: &UpstreamTy) -> proc_macro2::TokenStream {
         let subscription =
-            Self::type_or_else(&self.upstream_subscription, || self.subscription_ty());
+            Self::type_or_else(&self.upstream_subscription, || self.subscription_param());
         let item = Self::type_or_else(&self.upstream_item, || parse_quote! {Item});
         let error = Self::type_or_else(&self.upstream_error, || parse_quote! {Error});
+        let subscription = self.observer_view(kind, quote! {#subscription});
+        let item = self.observer_view(kind, quote! {#item});
         quote! {#subscription, #item, #error}
     }
 
-    fn downstream_params(&self) -> proc_macro2::TokenStream {
-        let subscription = Self::type_or_else(&self.subscription, || self.subscription_ty());
+    fn downstream_params(&self, kind: &UpstreamTy) -> proc_macro2::TokenStream {
+        let subscription = Self::type_or_else(&self.subscription, || self.subscription_param());
         let item = Self::type_or_else(&self.item, || parse_quote! {Item});
         let error = Self::type_or_else(&self.error, || parse_quote! {Error});
+        let subscription = self.observer_view(kind, quote! {#subscription});
+        let item = self.observer_view(kind, quote! {#item});
         quote! {#subscription, #item, #error}
     }
 
-    fn upstream_ty(&self) -> syn::Type {
+    /// Operators defined for both upstream types see the errors of flows wrapped in
+    /// `flow::Error`, so `Error` within their subscription and item types refers to
+    /// `flow::Error<Error>` for flows.
+    fn observer_view(
+        &self,
+        kind: &UpstreamTy,
+        tokens: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        if self.upstream == UpstreamTy::Both && *kind == UpstreamTy::Flow {
+            wrap_flow_error(tokens)
+        } else {
+            tokens
+        }
+    }
+
+    /// The name of the struct's generic parameter for the upstream type.
+    fn upstream_param(&self) -> syn::Type {
         match self.upstream {
-            UpstreamTy::Flow => parse_quote! { Flow },
-            UpstreamTy::Observable => parse_quote! { Observable },
+            UpstreamTy::Both => parse_quote! { Upstream },
+            ref kind => Self::upstream_ty(kind),
         }
     }
 
-    fn subscription_ty(&self) -> syn::Type {
+    /// The name of the struct's generic parameter for the cancellable or subscription type.
+    fn subscription_param(&self) -> syn::Type {
         match self.upstream {
+            UpstreamTy::Both => parse_quote! { Subscription },
+            ref kind => Self::subscription_ty(kind),
+        }
+    }
+
+    fn upstream_ty(kind: &UpstreamTy) -> syn::Type {
+        let ident = Self::upstream_ty_ident(kind);
+        parse_quote! { #ident }
+    }
+
+    fn subscription_ty(kind: &UpstreamTy) -> syn::Type {
+        match kind {
             UpstreamTy::Flow => parse_quote! { Subscription },
-            UpstreamTy::Observable => parse_quote! { Cancellable },
+            _ => parse_quote! { Cancellable },
+        }
+    }
+
+    fn raw_subscriber_trait(kind: &UpstreamTy) -> Ident {
+        format_ident!("Raw{}", Self::subscriber_trait(kind))
+    }
+
+    fn raw_adapter(kind: &UpstreamTy) -> syn::Path {
+        match kind {
+            UpstreamTy::Flow => parse_quote! { ::kled::subscriber::SubscriberAsRaw },
+            _ => parse_quote! { ::kled::observer::ObserverAsRaw },
+        }
+    }
+
+    fn method(&self) -> Option<Ident> {
+        self.method.as_ref().map(|s| match parse_str(&s.value()) {
+            Ok(ident) => ident,
+            Err(_) => abort!(s, "`#[operator]` method must be a valid identifier"),
+        })
+    }
+
+    /// The name of the generated extension trait, e.g. `MyOpExt` or `MyOpFlowExt` for the
+    /// method `my_op`.
+    fn extension_trait_ident(&self, method: &Ident, kind: &UpstreamTy) -> Ident {
+        let name: String = method
+            .to_string()
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect();
+        match self.upstream {
+            UpstreamTy::Both => format_ident!("{}{}Ext", name, Self::upstream_ty_ident(kind)),
+            _ => format_ident!("{}Ext", name),
+        }
+    }
+
+    fn upstream_ty_ident(kind: &UpstreamTy) -> Ident {
+        match kind {
+            UpstreamTy::Flow => format_ident!("Flow"),
+            _ => format_ident!("Observable"),
         }
     }
 
     fn subscriber_ident(&self, ident: &Ident) -> syn::Type {
-        let ident = format_ident!("{}{}", ident, self.subscriber_trait());
+        let ident = format_ident!("{}{}", ident, Self::subscriber_trait(&self.upstream));
         parse_quote! {#ident}
     }
 }
@@ -100,63 +196,268 @@ pub fn derive(args: &Args, item: TokenStream) -> TokenStream {
     let item = parse_macro_input!(item as ItemStruct);
     let mut result = quote! {};
     result.extend(derive_operator_struct(&args, &item));
+    for kind in args.upstream.kinds() {
+        result.extend(derive_operator_impl(&args, &item, &kind));
+        if let Some(method) = args.method() {
+            result.extend(derive_extension_trait(&args, &item, &kind, &method));
+        }
+    }
     result.into()
 }
 
+/// Returns the expressions accessing the upstream and the user defined fields of the
+/// generated struct.
+fn field_accesses(item: &ItemStruct) -> (proc_macro2::TokenStream, Vec<proc_macro2::TokenStream>) {
+    match &item.fields {
+        Fields::Unnamed(fields) => (
+            quote! { self.0 },
+            (1..=fields.unnamed.len())
+                .map(|i| {
+                    let index = Index::from(i);
+                    quote! { self.#index }
+                })
+                .collect(),
+        ),
+        fields => (
+            quote! { self.upstream },
+            fields
+                .iter()
+                .map(|f| {
+                    let ident = &f.ident;
+                    quote! { self.#ident }
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Replaces each unqualified `Error` in `tokens` with `::kled::flow::Error<Error>`.
+fn wrap_flow_error(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut result = proc_macro2::TokenStream::new();
+    let mut qualified = false;
+    for tree in tokens {
+        match tree {
+            TokenTree::Group(group) => {
+                let mut wrapped = Group::new(group.delimiter(), wrap_flow_error(group.stream()));
+                wrapped.set_span(group.span());
+                result.extend(Some(TokenTree::Group(wrapped)));
+                qualified = false;
+            }
+            TokenTree::Ident(ident) if ident == "Error" && !qualified => {
+                result.extend(quote! { ::kled::flow::Error<#ident> });
+            }
+            tree => {
+                qualified = matches!(&tree, TokenTree::Punct(punct) if punct.as_char() == ':');
+                result.extend(Some(tree));
+            }
+        }
+    }
+    result
+}
+
+/// Returns the generic parameters of the item without their bounds, e.g. to name the
+/// generated struct.
+fn generic_args(item: &ItemStruct) -> Vec<proc_macro2::TokenStream> {
+    item.generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                quote! { #ident }
+            }
+            GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                quote! { #lifetime }
+            }
+            GenericParam::Const(constant) => {
+                let ident = &constant.ident;
+                quote! { #ident }
+            }
+        })
+        .collect()
+}
+
 fn derive_operator_struct(args: &Args, item: &ItemStruct) -> proc_macro2::TokenStream {
     let attrs = &item.attrs;
     let vis = &item.vis;
     let ident = &item.ident;
-    let upstream_ty = args.upstream_ty();
-    let subscription_ty = args.subscription_ty();
+    let upstream_param = args.upstream_param();
+    let subscription_param = args.subscription_param();
+    let generic_params = &item.generics.params;
+    let where_clause = &item.generics.where_clause;
+    let phantom = quote! {
+        ::std::marker::PhantomData<(#upstream_param, #subscription_param, Item, Error, #generic_params)>
+    };
+    match &item.fields {
+        Fields::Unnamed(fields) => {
+            let fields = fields.unnamed.iter();
+            quote! {
+                #(#attrs)*
+                #[derive(::kled::derive_new::new)]
+                #vis struct #ident<#upstream_param, #subscription_param, Item, Error, #generic_params>(
+                    #upstream_param,
+                    #(#fields,)*
+                    #phantom,
+                )
+                #where_clause;
+            }
+        }
+        fields => {
+            let fields = fields.iter();
+            quote! {
+                #(#attrs)*
+                #[derive(::kled::derive_new::new)]
+                #vis struct #ident<#upstream_param, #subscription_param, Item, Error, #generic_params>
+                #where_clause
+                {
+                    upstream: #upstream_param,
+                    #(#fields,)*
+                    phantom: #phantom,
+                }
+            }
+        }
+    }
+}
+
+fn derive_operator_impl(
+    args: &Args,
+    item: &ItemStruct,
+    kind: &UpstreamTy,
+) -> proc_macro2::TokenStream {
+    let ident = &item.ident;
+    let upstream_param = args.upstream_param();
+    let subscription_param = args.subscription_param();
+    let upstream_ty = Args::upstream_ty(kind);
+    let subscription_ty = Args::subscription_ty(kind);
     let generic_params = &item.generics.params;
     let generic_params_iter = item.generics.params.iter();
     let (_, _, where_clause) = item.generics.split_for_impl();
     let predicates = where_clause.map(|where_clause| &where_clause.predicates);
-    let fields = match &item.fields {
-        Fields::Unnamed(fields) => abort!(
-            fields,
-            "`#[operator]` deriving from structs with unnamed fields is not yet implemented"
-        ),
-        fields => fields.iter(),
-    };
-    let subscriber_trait = args.subscriber_trait();
-    let upstream_params = args.upstream_params();
-    let downstream_params = args.downstream_params();
+    let (upstream, fields) = field_accesses(item);
+    let subscriber_trait = Args::subscriber_trait(kind);
+    let upstream_params = args.upstream_params(kind);
+    let downstream_params = args.downstream_params(kind);
     let subscriber_ident = args
         .subscriber()
         .unwrap_or_else(|| args.subscriber_ident(&ident));
-    let field_idents = fields.clone().map(|f| &f.ident);
-    quote! {
-        #(#attrs)*
-        #[derive(new)]
-        #vis struct #ident<#upstream_ty, #subscription_ty, Item, Error, #generic_params>
-        #where_clause
-        {
-            upstream: #upstream_ty,
-            #(#fields,)*
-            phantom: ::std::marker::PhantomData<(#upstream_ty, #subscription_ty, Item, Error, #generic_params)>,
+    // Operators defined for both upstream types only provide an observer, which is reused
+    // for flows by passing the subscription through and wrapping errors in flow::Error.
+    let subscriber = if args.upstream == UpstreamTy::Both && *kind == UpstreamTy::Flow {
+        quote! {
+            ::kled::subscriber::ObserverAsSubscriber::new(#subscriber_ident::new(
+                ::kled::subscriber::SubscriberAsObserver::new(downstream),
+                #(#fields),*
+            ))
         }
+    } else {
+        quote! { #subscriber_ident::new(downstream, #(#fields),*) }
+    };
+    let subscribe = if args.raw {
+        let raw_subscriber_trait = Args::raw_subscriber_trait(kind);
+        let raw_adapter = Args::raw_adapter(kind);
+        quote! {
+            async fn subscribe<Downstream>(self, downstream: Downstream)
+            where
+                Downstream: ::kled::core::#subscriber_trait<#downstream_params> + Send + 'static,
+            {
+                ::kled::core::#upstream_ty::subscribe_raw(self, #raw_adapter::new(downstream)).await
+            }
 
+            async fn subscribe_raw<Downstream>(self, downstream: Downstream)
+            where
+                Self: Sized,
+                Downstream: ::kled::core::#raw_subscriber_trait<#downstream_params> + Send + 'static,
+            {
+                #upstream.subscribe_raw(#subscriber).await
+            }
+        }
+    } else {
+        quote! {
+            async fn subscribe<Downstream>(self, downstream: Downstream)
+            where
+                Downstream: ::kled::core::#subscriber_trait<#downstream_params> + Send + 'static,
+            {
+                #upstream.subscribe(#subscriber).await
+            }
+        }
+    };
+    quote! {
         #[automatically_derived]
-        #[async_trait::async_trait]
-        impl<#upstream_ty, #subscription_ty, Item, Error, #generic_params>
-            core::#upstream_ty<#downstream_params>
-        for #ident<#upstream_ty, #subscription_ty, Item, Error, #generic_params>
+        #[::kled::async_trait::async_trait]
+        impl<#upstream_param, #subscription_param, Item, Error, #generic_params>
+            ::kled::core::#upstream_ty<#downstream_params>
+        for #ident<#upstream_param, #subscription_param, Item, Error, #generic_params>
         where
-            #upstream_ty: core::#upstream_ty<#upstream_params> + Send,
-            #subscription_ty: core::#subscription_ty + Send + Sync + 'static,
+            #upstream_param: ::kled::core::#upstream_ty<#upstream_params> + Send,
+            #subscription_param: ::kled::core::#subscription_ty + Send + Sync + 'static,
             Item: Send + 'static,
             Error: Send + 'static,
             #(#generic_params_iter: Send + 'static,)*
             #predicates
         {
-            async fn subscribe<Downstream>(self, downstream: Downstream)
+            #subscribe
+        }
+    }
+}
+
+fn derive_extension_trait(
+    args: &Args,
+    item: &ItemStruct,
+    kind: &UpstreamTy,
+    method: &Ident,
+) -> proc_macro2::TokenStream {
+    let vis = &item.vis;
+    let ident = &item.ident;
+    let trait_ident = args.extension_trait_ident(method, kind);
+    let upstream_ty = Args::upstream_ty(kind);
+    let subscription_ty = Args::subscription_ty(kind);
+    let generic_params = &item.generics.params;
+    let generic_params_iter = item.generics.params.iter();
+    let generic_args = generic_args(item);
+    let (_, _, where_clause) = item.generics.split_for_impl();
+    let predicates = where_clause.map(|where_clause| &where_clause.predicates);
+    let arg_idents: Vec<Ident> = match &item.fields {
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|i| format_ident!("arg{}", i))
+            .collect(),
+        fields => fields.iter().map(|f| f.ident.clone().unwrap()).collect(),
+    };
+    let arg_types = item.fields.iter().map(|f| &f.ty);
+    let doc = format!(
+        "Extension trait providing [`{}`] as a builder method on any `{}`.",
+        ident,
+        Args::upstream_ty_ident(kind)
+    );
+    quote! {
+        #[doc = #doc]
+        #vis trait #trait_ident<#subscription_ty, Item, Error>:
+            ::kled::core::#upstream_ty<#subscription_ty, Item, Error>
+        where
+            #subscription_ty: ::kled::core::#subscription_ty + Send + Sync + 'static,
+            Item: Send + 'static,
+            Error: Send + 'static,
+        {
+            fn #method<#generic_params>(
+                self,
+                #(#arg_idents: #arg_types),*
+            ) -> #ident<Self, #subscription_ty, Item, Error, #(#generic_args),*>
             where
-                Downstream: core::#subscriber_trait<#downstream_params> + Send + 'static,
+                Self: Sized,
+                #(#generic_params_iter: Send + 'static,)*
+                #predicates
             {
-                self.upstream.subscribe(#subscriber_ident::new(downstream, #(self.#field_idents),*)).await
+                #ident::new(self, #(#arg_idents),*)
             }
         }
+
+        impl<T, #subscription_ty, Item, Error> #trait_ident<#subscription_ty, Item, Error> for T
+        where
+            T: ::kled::core::#upstream_ty<#subscription_ty, Item, Error>,
+            #subscription_ty: ::kled::core::#subscription_ty + Send + Sync + 'static,
+            Item: Send + 'static,
+            Error: Send + 'static,
+        {
+        }
     }
 }
@@ -0,0 +1,229 @@
+use darling::FromMeta;
+use proc_macro::TokenStream;
+use proc_macro_error::abort;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, parse_str, GenericArgument, Ident, ImplItem, ItemImpl,
+    PathArguments, Type,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DownstreamTy {
+    Observer,
+    Subscriber,
+}
+
+impl DownstreamTy {
+    fn default_field(self) -> Ident {
+        match self {
+            DownstreamTy::Observer => format_ident!("observer"),
+            DownstreamTy::Subscriber => format_ident!("subscriber"),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            DownstreamTy::Observer => "observer",
+            DownstreamTy::Subscriber => "subscriber",
+        }
+    }
+}
+
+#[derive(Default, FromMeta)]
+#[darling(default)]
+pub struct Args {
+    downstream: Option<syn::LitStr>,
+}
+
+impl Args {
+    fn downstream(&self, ty: DownstreamTy) -> Ident {
+        self.downstream
+            .as_ref()
+            .map(|s| match parse_str(&s.value()) {
+                Ok(ident) => ident,
+                Err(_) => abort!(
+                    s,
+                    "`#[{}]` downstream must be a valid field name",
+                    ty.name()
+                ),
+            })
+            .unwrap_or_else(|| ty.default_field())
+    }
+}
+
+pub fn forward(ty: DownstreamTy, args: &Args, item: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(item as ItemImpl);
+    let downstream = args.downstream(ty);
+    let (trait_ident, trait_args) = implemented_trait(ty, &item);
+    let raw = trait_ident.to_string().starts_with("Raw");
+    let defined: Vec<Ident> = item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Method(method) => Some(method.sig.ident.clone()),
+            _ => None,
+        })
+        .collect();
+    let methods = if raw {
+        raw_methods(ty, &trait_args, &downstream)
+    } else {
+        async_methods(ty, &trait_args, &downstream)
+    };
+    for (ident, method) in methods {
+        if !defined.contains(&ident) {
+            item.items.push(method);
+        }
+    }
+    quote!(#item).into()
+}
+
+/// Returns the name and the generic arguments of the implemented trait, which must be the
+/// (raw) observer or subscriber trait.
+fn implemented_trait(ty: DownstreamTy, item: &ItemImpl) -> (Ident, Vec<Type>) {
+    let path = match &item.trait_ {
+        Some((_, path, _)) => path,
+        None => abort!(
+            item.self_ty,
+            "`#[{}]` must be applied to a trait implementation",
+            ty.name()
+        ),
+    };
+    let segment = path.segments.last().unwrap();
+    let expected = match ty {
+        DownstreamTy::Observer => ["Observer", "RawObserver"],
+        DownstreamTy::Subscriber => ["Subscriber", "RawSubscriber"],
+    };
+    if !expected.iter().any(|name| segment.ident == name) {
+        abort!(
+            segment.ident,
+            "`#[{}]` expected an implementation of `{}` or `{}`",
+            ty.name(),
+            expected[0],
+            expected[1]
+        );
+    }
+    let args: Vec<Type> = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    if args.len() != 3 {
+        abort!(
+            segment,
+            "`#[{}]` expected `{}` to have three type arguments",
+            ty.name(),
+            segment.ident
+        );
+    }
+    (segment.ident.clone(), args)
+}
+
+fn error_ty(ty: DownstreamTy, error: &Type) -> Type {
+    match ty {
+        DownstreamTy::Observer => error.clone(),
+        DownstreamTy::Subscriber => parse_quote! { ::kled::flow::Error<#error> },
+    }
+}
+
+fn async_methods(ty: DownstreamTy, args: &[Type], downstream: &Ident) -> Vec<(Ident, ImplItem)> {
+    let (subscription, item, error) = (&args[0], &args[1], &args[2]);
+    let error = error_ty(ty, error);
+    let subscription_arg = match ty {
+        DownstreamTy::Observer => format_ident!("cancellable"),
+        DownstreamTy::Subscriber => format_ident!("subscription"),
+    };
+    vec![
+        (
+            format_ident!("on_subscribe"),
+            parse_quote! {
+                async fn on_subscribe(&mut self, #subscription_arg: #subscription) {
+                    self.#downstream.on_subscribe(#subscription_arg).await;
+                }
+            },
+        ),
+        (
+            format_ident!("on_next"),
+            parse_quote! {
+                async fn on_next(&mut self, item: #item) {
+                    self.#downstream.on_next(item).await;
+                }
+            },
+        ),
+        (
+            format_ident!("on_error"),
+            parse_quote! {
+                async fn on_error(&mut self, error: #error) {
+                    self.#downstream.on_error(error).await;
+                }
+            },
+        ),
+        (
+            format_ident!("on_completed"),
+            parse_quote! {
+                async fn on_completed(&mut self) {
+                    self.#downstream.on_completed().await;
+                }
+            },
+        ),
+    ]
+}
+
+fn raw_methods(ty: DownstreamTy, args: &[Type], downstream: &Ident) -> Vec<(Ident, ImplItem)> {
+    let (subscription, item, error) = (&args[0], &args[1], &args[2]);
+    let error = error_ty(ty, error);
+    let subscription_arg = match ty {
+        DownstreamTy::Observer => format_ident!("cancellable"),
+        DownstreamTy::Subscriber => format_ident!("subscription"),
+    };
+    vec![
+        (
+            format_ident!("poll_ready"),
+            parse_quote! {
+                fn poll_ready(
+                    &mut self,
+                    cx: &mut ::std::task::Context<'_>,
+                ) -> ::std::task::Poll<()> {
+                    self.#downstream.poll_ready(cx)
+                }
+            },
+        ),
+        (
+            format_ident!("start_subscribe"),
+            parse_quote! {
+                fn start_subscribe(&mut self, #subscription_arg: #subscription) {
+                    self.#downstream.start_subscribe(#subscription_arg);
+                }
+            },
+        ),
+        (
+            format_ident!("start_next"),
+            parse_quote! {
+                fn start_next(&mut self, item: #item) {
+                    self.#downstream.start_next(item);
+                }
+            },
+        ),
+        (
+            format_ident!("start_error"),
+            parse_quote! {
+                fn start_error(&mut self, error: #error) {
+                    self.#downstream.start_error(error);
+                }
+            },
+        ),
+        (
+            format_ident!("start_completed"),
+            parse_quote! {
+                fn start_completed(&mut self) {
+                    self.#downstream.start_completed();
+                }
+            },
+        ),
+    ]
+}
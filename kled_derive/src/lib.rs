@@ -9,7 +9,9 @@ use proc_macro_error::*;
 use syn::{parse_macro_input, AttributeArgs};
 
 mod derive;
+mod forward;
 use derive::Args;
+use forward::DownstreamTy;
 
 #[proc_macro_attribute]
 #[proc_macro_error]
@@ -25,3 +27,40 @@ pub fn operator(args: TokenStream, item: TokenStream) -> TokenStream {
 
     derive::derive(&args, item)
 }
+
+/// Completes an implementation of `Observer` or `RawObserver` by forwarding each signal that
+/// is not implemented explicitly to the downstream observer stored in the field `observer`.
+/// Another field may be chosen via `#[observer(downstream = "field")]`.
+///
+/// When implementing the async `Observer` trait, this attribute must be placed above
+/// `#[async_trait]`.
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn observer(args: TokenStream, item: TokenStream) -> TokenStream {
+    forward_impl(DownstreamTy::Observer, args, item)
+}
+
+/// Completes an implementation of `Subscriber` or `RawSubscriber` by forwarding each signal
+/// that is not implemented explicitly to the downstream subscriber stored in the field
+/// `subscriber`. Another field may be chosen via `#[subscriber(downstream = "field")]`.
+///
+/// When implementing the async `Subscriber` trait, this attribute must be placed above
+/// `#[async_trait]`.
+#[proc_macro_attribute]
+#[proc_macro_error]
+pub fn subscriber(args: TokenStream, item: TokenStream) -> TokenStream {
+    forward_impl(DownstreamTy::Subscriber, args, item)
+}
+
+fn forward_impl(ty: DownstreamTy, args: TokenStream, item: TokenStream) -> TokenStream {
+    let attr_args = parse_macro_input!(args as AttributeArgs);
+
+    let args = match forward::Args::from_list(&attr_args) {
+        Ok(v) => v,
+        Err(e) => {
+            return e.write_errors().into();
+        }
+    };
+
+    forward::forward(ty, &args, item)
+}
@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate kled_derive;
+
+use std::task::{Context, Poll};
+
+pub struct MissingDownstream<Observer> {
+    inner: Observer,
+}
+
+#[observer]
+impl<Cancellable, Item, Error, Observer> kled::core::RawObserver<Cancellable, Item, Error>
+    for MissingDownstream<Observer>
+where
+    Observer: kled::core::RawObserver<Cancellable, Item, Error>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn start_subscribe(&mut self, cancellable: Cancellable) {
+        self.inner.start_subscribe(cancellable);
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.inner.start_next(item);
+    }
+
+    fn start_error(&mut self, error: Error) {
+        self.inner.start_error(error);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate kled_derive;
+
+pub struct WrongTrait<Subscriber> {
+    observer: Subscriber,
+}
+
+#[observer]
+impl<Subscriber> Clone for WrongTrait<Subscriber>
+where
+    Subscriber: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            observer: self.observer.clone(),
+        }
+    }
+}
+
+fn main() {}
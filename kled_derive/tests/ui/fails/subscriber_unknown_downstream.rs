@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate kled_derive;
+
+use std::task::{Context, Poll};
+
+pub struct UnknownDownstream<Subscriber> {
+    subscriber: Subscriber,
+}
+
+#[subscriber(downstream = "downstream")]
+impl<Subscription, Item, Error, Subscriber> kled::core::RawSubscriber<Subscription, Item, Error>
+    for UnknownDownstream<Subscriber>
+where
+    Subscriber: kled::core::RawSubscriber<Subscription, Item, Error>,
+{
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.subscriber.poll_ready(cx)
+    }
+
+    fn start_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.start_subscribe(subscription);
+    }
+
+    fn start_next(&mut self, item: Item) {
+        self.subscriber.start_next(item);
+    }
+
+    fn start_error(&mut self, error: kled::flow::Error<Error>) {
+        self.subscriber.start_error(error);
+    }
+}
+
+fn main() {}
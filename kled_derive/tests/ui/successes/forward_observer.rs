@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate kled_derive;
+
+use kled::async_trait::async_trait;
+
+pub struct CountingObserver<Observer> {
+    observer: Observer,
+    count: usize,
+}
+
+#[observer]
+#[async_trait]
+impl<Cancellable, Item, Error, Observer> kled::core::Observer<Cancellable, Item, Error>
+    for CountingObserver<Observer>
+where
+    Observer: kled::core::Observer<Cancellable, Item, Error> + Send,
+    Cancellable: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_next(&mut self, item: Item) {
+        self.count += 1;
+        self.observer.on_next(item).await;
+    }
+}
+
+pub struct CountingSubscriber<Subscriber> {
+    downstream: Subscriber,
+    count: usize,
+}
+
+#[subscriber(downstream = "downstream")]
+impl<Subscription, Item, Error, Subscriber> kled::core::RawSubscriber<Subscription, Item, Error>
+    for CountingSubscriber<Subscriber>
+where
+    Subscriber: kled::core::RawSubscriber<Subscription, Item, Error>,
+{
+    fn start_next(&mut self, item: Item) {
+        self.count += 1;
+        self.downstream.start_next(item);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate kled_derive;
+
+use kled::async_trait::async_trait;
+use kled::derive_new::new;
+use kled::prelude::*;
+
+#[operator(type = "observable", item = "ItemOut", method = "map_tuple")]
+pub struct MapTuple<ItemOut, UnaryOp>(UnaryOp)
+where
+    UnaryOp: FnMut(Item) -> ItemOut + Send;
+
+#[derive(new)]
+struct MapTupleObserver<Observer, UnaryOp> {
+    observer: Observer,
+    unary_op: UnaryOp,
+}
+
+#[async_trait]
+impl<Cancellable, ItemIn, Observer, ItemOut, Error, UnaryOp>
+    kled::core::Observer<Cancellable, ItemIn, Error> for MapTupleObserver<Observer, UnaryOp>
+where
+    Observer: kled::core::Observer<Cancellable, ItemOut, Error> + Send,
+    UnaryOp: FnMut(ItemIn) -> ItemOut + Send,
+    Cancellable: Send + 'static,
+    ItemIn: Send + 'static,
+    ItemOut: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, cancellable: Cancellable) {
+        self.observer.on_subscribe(cancellable).await;
+    }
+
+    async fn on_next(&mut self, item: ItemIn) {
+        self.observer.on_next((self.unary_op)(item)).await;
+    }
+
+    async fn on_error(&mut self, error: Error) {
+        self.observer.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.observer.on_completed().await;
+    }
+}
+
+#[allow(dead_code)]
+async fn subscribe() {
+    vec![0, 1, 2]
+        .into_observable()
+        .map_tuple(|a| a + 1)
+        .subscribe_next(|_| {})
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate kled_derive;
+
+use kled::async_trait::async_trait;
+use kled::derive_new::new;
+use kled::flow;
+use kled::prelude::*;
+
+#[operator(type = "flow", method = "identity")]
+pub struct Identity;
+
+#[derive(new)]
+struct IdentitySubscriber<Subscriber> {
+    subscriber: Subscriber,
+}
+
+#[async_trait]
+impl<Subscription, Item, Error, Subscriber> kled::core::Subscriber<Subscription, Item, Error>
+    for IdentitySubscriber<Subscriber>
+where
+    Subscriber: kled::core::Subscriber<Subscription, Item, Error> + Send,
+    Subscription: Send + 'static,
+    Item: Send + 'static,
+    Error: Send + 'static,
+{
+    async fn on_subscribe(&mut self, subscription: Subscription) {
+        self.subscriber.on_subscribe(subscription).await;
+    }
+
+    async fn on_next(&mut self, item: Item) {
+        self.subscriber.on_next(item).await;
+    }
+
+    async fn on_error(&mut self, error: flow::Error<Error>) {
+        self.subscriber.on_error(error).await;
+    }
+
+    async fn on_completed(&mut self) {
+        self.subscriber.on_completed().await;
+    }
+}
+
+#[allow(dead_code)]
+async fn subscribe() {
+    vec![0, 1, 2]
+        .into_flow()
+        .identity()
+        .subscribe_next(|_| {})
+        .await;
+}
+
+fn main() {}